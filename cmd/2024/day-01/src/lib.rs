@@ -0,0 +1,53 @@
+pub const INPUT: &str = include_str!("input.txt");
+
+/// Sums up the distance between the n-th smallest numbers of both lists.
+pub fn part_1(parsed_input: &ParsedInput) -> i32 {
+    let mut total_distance = 0;
+    for (left, right) in parsed_input.left.iter().zip(&parsed_input.right) {
+        let distance = (left - right).abs();
+        total_distance += distance;
+    }
+
+    total_distance
+}
+
+/// Sums up every left number multiplied by how often it appears in the right list.
+pub fn part_2(parsed_input: &ParsedInput) -> i32 {
+    let mut total_similarity = 0;
+    for num in &parsed_input.left {
+        let count = parsed_input.right.iter().filter(|v| *v == num).count() as i32;
+        let similarity = num * count;
+        total_similarity += similarity;
+    }
+
+    total_similarity
+}
+
+/// Both location id lists, each sorted ascending.
+pub struct ParsedInput {
+    pub left: Vec<i32>,
+    pub right: Vec<i32>,
+}
+
+pub fn parse_input(input: &str) -> ParsedInput {
+    let mut left = Vec::new();
+    let mut right = Vec::new();
+
+    for line in input.lines() {
+        let split = line
+            .split(' ')
+            .filter(|v| !v.is_empty())
+            .collect::<Vec<_>>();
+        assert!(split.len() == 2);
+
+        left.push(split[0].parse().unwrap());
+        right.push(split[1].parse().unwrap());
+    }
+
+    left.sort();
+    right.sort();
+
+    assert!(left.len() == right.len());
+
+    ParsedInput { left, right }
+}
@@ -1,14 +1,4 @@
-const INPUT: &str = include_str!("input.txt");
-
-fn main() {
-    let input = parse_input();
-
-    println!("part 1");
-    part_1(input.clone());
-    println!("=======");
-    println!("part 2");
-    part_2(input);
-}
+pub const INPUT: &str = include_str!("input.txt");
 
 #[derive(PartialEq, Eq, Clone, Copy)]
 enum Mode {
@@ -16,36 +6,38 @@ enum Mode {
     Decreasing,
 }
 
-fn part_1(lines: Vec<Vec<i32>>) {
-    let safe_lines = lines.into_iter().filter(|v| is_safe(v)).count();
-    println!("Safe lines: {safe_lines}")
+/// Counts the reports that are safe as they are.
+pub fn part_1(lines: &ParsedInput) -> usize {
+    lines.iter().filter(|v| is_safe(v)).count()
 }
 
-fn part_2(lines: Vec<Vec<i32>>) {
-    let safe_lines = lines.into_iter().filter(|v| is_safe_one_err(v)).count();
-    println!("Safe lines: {safe_lines}")
+/// Counts the reports that are safe with at most one level removed.
+pub fn part_2(lines: &ParsedInput) -> usize {
+    lines.iter().filter(|v| is_safe_one_err(v)).count()
 }
 
-fn is_safe_one_err(line: &Vec<i32>) -> bool{
-    if is_safe(&line) {
+/// Whether the report is safe, or becomes safe when removing a single level.
+pub fn is_safe_one_err(line: &[i32]) -> bool {
+    if is_safe(line) {
         return true;
     }
 
     let mut removing = 0;
     while removing < line.len() {
-        let mut using_line = line.clone();
+        let mut using_line = line.to_vec();
         using_line.remove(removing);
         removing += 1;
 
-        if is_safe(&using_line){
+        if is_safe(&using_line) {
             return true;
         }
     }
 
-    return false;
+    false
 }
 
-fn is_safe(line: &Vec<i32>) -> bool {
+/// Whether the levels are strictly increasing or decreasing by 1 to 3 each step.
+pub fn is_safe(line: &[i32]) -> bool {
     let mut last_num = None;
     let mut mode: Option<Mode> = None;
 
@@ -85,10 +77,13 @@ fn is_safe(line: &Vec<i32>) -> bool {
     true
 }
 
-fn parse_input() -> Vec<Vec<i32>> {
+/// One report of levels per line.
+pub type ParsedInput = Vec<Vec<i32>>;
+
+pub fn parse_input(input: &str) -> ParsedInput {
     let mut lines = Vec::new();
 
-    for line in INPUT.lines() {
+    for line in input.lines() {
         let split = line
             .split(' ')
             // .filter(|v| !v.is_empty())
@@ -1,22 +1,10 @@
-const INPUT: &str = include_str!("input.txt");
+pub const INPUT: &str = include_str!("input.txt");
 
-fn main() {
-    println!("part 1");
-    part_1();
-
-    println!("=======");
-    println!("part 2");
-    part_2();
-}
-
-fn part_1() {
-    let mut remainder = &INPUT[..];
+/// Sums up the results of every valid `mul(x,y)` instruction.
+pub fn part_1(parsed_input: &ParsedInput) -> i32 {
+    let mut remainder = &parsed_input[..];
     let mut sum = 0;
-    loop {
-        let Some(next_open_index) = remainder.find("mul(") else {
-            break;
-        };
-
+    while let Some(next_open_index) = remainder.find("mul(") {
         let buf_start_open = &remainder[next_open_index..];
         remainder = &remainder[next_open_index + "mul(".len()..];
 
@@ -24,16 +12,17 @@ fn part_1() {
             break;
         };
 
-        if let Some(mul_result) = parse_mul_instruction(&buf_start_open[..=next_close_index]){
+        if let Some(mul_result) = parse_mul_instruction(&buf_start_open[..=next_close_index]) {
             sum += mul_result;
         }
     }
 
-    println!("Result: {sum}")
+    sum
 }
 
-fn part_2() {
-    let mut remainder = &INPUT[..];
+/// Like [`part_1`], but skips `mul` instructions between a `don't()` and the next `do()`.
+pub fn part_2(parsed_input: &ParsedInput) -> i32 {
+    let mut remainder = &parsed_input[..];
     let mut sum = 0;
     loop {
         let next_dont = remainder.find("don't()");
@@ -58,28 +47,33 @@ fn part_2() {
 
         remainder = &remainder[next_open_index + "mul(".len()..];
 
-        
-
         let Some(next_close_index) = buf_start_open.find(")") else {
             break;
         };
 
-        if let Some(mul_result) = parse_mul_instruction(&buf_start_open[..=next_close_index]){
+        if let Some(mul_result) = parse_mul_instruction(&buf_start_open[..=next_close_index]) {
             sum += mul_result;
         }
     }
 
-    println!("Result: {sum}")
+    sum
 }
 
-// Takes in a "mul(1,2)" str and returns the result
-fn parse_mul_instruction(s: &str) -> Option<i32> {
+/// Takes in a "mul(1,2)" str and returns the result
+pub fn parse_mul_instruction(s: &str) -> Option<i32> {
     // just the numbers (e.g "1,2")
-    let arg_buffer_str = &s["mul(".len()..s.len()-1];
-    
+    let arg_buffer_str = &s["mul(".len()..s.len() - 1];
+
     let comma_index = arg_buffer_str.find(",")?;
     let num_pre_comma = &arg_buffer_str[..comma_index].parse::<i32>().ok()?;
     let num_post_comma = &arg_buffer_str[comma_index + 1..].parse::<i32>().ok()?;
 
     Some(num_pre_comma * num_post_comma)
 }
+
+/// The corrupted memory, the instructions are scanned straight from the text.
+pub type ParsedInput = String;
+
+pub fn parse_input(input: &str) -> ParsedInput {
+    input.to_string()
+}
@@ -0,0 +1,14 @@
+use aoc_2024_day_03::{parse_input, part_1, part_2, INPUT};
+
+fn main() {
+    common::log::init_from_args();
+
+    let parsed = parse_input(&common::input::load(INPUT));
+
+    println!("part 1");
+    println!("Result: {}", part_1(&parsed));
+
+    println!("=======");
+    println!("part 2");
+    println!("Result: {}", part_2(&parsed));
+}
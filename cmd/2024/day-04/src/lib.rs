@@ -0,0 +1,117 @@
+use common::grid::Grid;
+
+pub const INPUT: &str = include_str!("input.txt");
+
+/// Counts every occurrence of XMAS in any direction.
+pub fn part_1(input: &ParsedInput) -> usize {
+    let word = &['X', 'M', 'A', 'S'];
+    find_word(input, word)
+}
+
+/// Counts every X shaped pair of MAS.
+pub fn part_2(input: &ParsedInput) -> usize {
+    // Both MAS read top to bottom, every other way to write them is a rotation or reflection
+    let mask = Grid::parse("M.S\n.A.\nM.S", |c| (c != '.').then_some(c));
+    let masks = mask.symmetries().collect::<Vec<_>>();
+
+    let mut count = 0;
+    for y in 0..input.height() {
+        for x in 0..input.width() {
+            for mask in &masks {
+                if is_mask_match(input, mask, x, y) {
+                    count += 1;
+                    // println!("Found mask x{x} y{y}");
+                }
+            }
+        }
+    }
+
+    count
+}
+
+/// The word search as a grid of characters.
+pub type ParsedInput = Grid<char>;
+
+pub fn parse_input(input: &str) -> ParsedInput {
+    Grid::parse(input, |c| c)
+}
+
+const DIRECTIONS: &[(i32, i32)] = &[
+    (1, 0),  // horizontal
+    (0, 1),  // vertical
+    (1, 1),  // diagonal right
+    (-1, 1), // diagonal left
+];
+
+/// Counts the occurrences of `word` in all 8 directions.
+pub fn find_word(input: &Grid<char>, word: &[char]) -> usize {
+    let mut count = 0;
+    for y in 0..input.height() {
+        for x in 0..input.width() {
+            for (move_x, move_y) in DIRECTIONS {
+                if is_word_match(input, word, x, y, *move_x, *move_y) {
+                    // println!("Found word {x}.{y}: dir: {move_x}.{move_y}");
+                    count += 1;
+                }
+
+                // Reverse
+                if is_word_match(input, word, x, y, -*move_x, -*move_y) {
+                    // println!("Found word reverse {x}.{y}: dir: {move_x}.{move_y}");
+                    count += 1;
+                }
+            }
+        }
+    }
+
+    count
+}
+
+/// Whether `word` can be read starting at the given position, moving by the given step.
+pub fn is_word_match(
+    input: &Grid<char>,
+    word: &[char],
+    start_x: usize,
+    start_y: usize,
+    move_x: i32,
+    move_y: i32,
+) -> bool {
+    let mut cur_x = start_x as i32;
+    let mut cur_y = start_y as i32;
+
+    for c in word {
+        if !input.in_bounds(cur_x as i64, cur_y as i64) {
+            return false;
+        }
+
+        if input[(cur_x as usize, cur_y as usize)] != *c {
+            return false;
+        }
+
+        cur_x += move_x;
+        cur_y += move_y;
+    }
+
+    true
+}
+
+/// Whether the mask matches with its top left corner at the given position, `None` matches anything.
+pub fn is_mask_match(
+    input: &Grid<char>,
+    char_mask: &Grid<Option<char>>,
+    start_x: usize,
+    start_y: usize,
+) -> bool {
+    for ((mask_x, mask_y), mask_cell) in char_mask.cells() {
+        let Some(input_char) = input.get(mask_x + start_x, mask_y + start_y) else {
+            return false;
+        };
+
+        if let Some(filter_char) = mask_cell {
+            if filter_char != input_char {
+                return false;
+            }
+        }
+    }
+
+    true
+}
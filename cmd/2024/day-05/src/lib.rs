@@ -1,60 +1,52 @@
-const INPUT: &str = include_str!("input.txt");
+pub const INPUT: &str = include_str!("input.txt");
 
-fn main() {
-    println!("part 1");
-    part_1();
-
-    println!("=======");
-    println!("part 2");
-    part_2();
-}
-
-fn part_1() {
+/// Sums up the middle page of every correctly ordered update.
+pub fn part_1(parsed: &ParsedInput) -> i32 {
     let mut total = 0;
 
-    let parsed = parse_input();
     for update in &parsed.updates {
-        if parsed.is_valid_update(&update) {
+        if parsed.is_valid_update(update) {
             let middle_index = update.len() / 2;
             total += update[middle_index];
         }
     }
 
-    println!("Total: {total}");
+    total
 }
 
-fn part_2() {
+/// Sums up the middle page of every incorrectly ordered update after fixing its order.
+pub fn part_2(parsed: &ParsedInput) -> i32 {
     let mut total = 0;
 
-    let parsed = parse_input();
     for update in &parsed.updates {
-        if !parsed.is_valid_update(&update) {
-            let fixed = parsed.fix_update(&update);
+        if !parsed.is_valid_update(update) {
+            let fixed = parsed.fix_update(update);
             let middle_index = fixed.len() / 2;
             total += fixed[middle_index];
         }
     }
 
-    println!("Total: {total}");
+    total
 }
 
-struct OrderRule {
-    before: i32,
-    after: i32,
+/// A `before|after` page ordering rule.
+pub struct OrderRule {
+    pub before: i32,
+    pub after: i32,
 }
 
-struct ParsedInput {
-    rules: Vec<OrderRule>,
-    updates: Vec<Vec<i32>>,
+pub struct ParsedInput {
+    pub rules: Vec<OrderRule>,
+    pub updates: Vec<Vec<i32>>,
 }
 
-fn parse_input() -> ParsedInput {
+pub fn parse_input(input: &str) -> ParsedInput {
     let mut parsing_updates = false;
 
     let mut rules = Vec::new();
     let mut updates = Vec::new();
 
-    for line in INPUT.lines() {
+    for line in input.lines() {
         if line.is_empty() {
             parsing_updates = true;
             continue;
@@ -91,12 +83,12 @@ impl ParsedInput {
         true
     }
 
-    fn find_bad_update_index(&self, update: &Vec<i32>) -> Option<(usize, usize)> {
+    fn find_bad_update_index(&self, update: &[i32]) -> Option<(usize, usize)> {
         for i in 0..update.len() {
             for j in i + 1..update.len() {
                 // make sure j prints after i
                 if !self.check_order_rules(update[i], update[j]) {
-                    return Some((i,j));
+                    return Some((i, j));
                 }
             }
         }
@@ -104,19 +96,19 @@ impl ParsedInput {
         None
     }
 
-    fn is_valid_update(&self, update: &Vec<i32>) -> bool {
+    /// Whether no pair of pages in the update breaks an ordering rule.
+    pub fn is_valid_update(&self, update: &[i32]) -> bool {
         self.find_bad_update_index(update).is_none()
     }
 
-    fn fix_update(&self, update: &Vec<i32>) -> Vec<i32> {
-        let mut fixed = update.clone();
+    /// Returns the update reordered so that it follows all the rules.
+    pub fn fix_update(&self, update: &[i32]) -> Vec<i32> {
+        let mut fixed = update.to_vec();
 
         // Swap bad order until it's correct... yeah probably a faster way to do this
-        while let Some((wants_before_i, wants_after_i)) = self.find_bad_update_index(&fixed){
+        while let Some((wants_before_i, wants_after_i)) = self.find_bad_update_index(&fixed) {
             // Simply swap them i guess
-            let temp  = fixed[wants_before_i];
-            fixed[wants_before_i] = fixed[wants_after_i];
-            fixed[wants_after_i] = temp;
+            fixed.swap(wants_before_i, wants_after_i);
         }
 
         fixed
@@ -0,0 +1,202 @@
+use common::{
+    bits::BitGrid,
+    grid_parser::{Count, GridParser},
+};
+
+pub const INPUT: &str = include_str!("input.txt");
+
+/// Counts the distinct tiles the guard visits before leaving the map.
+pub fn part_1(initial_map: &MapState) -> usize {
+    let mut map = initial_map.clone();
+    loop {
+        let (is_in_bounds, _) = map.tick();
+        if !is_in_bounds {
+            break;
+        }
+    }
+
+    map.visited_tiles.count()
+}
+
+/// Counts the positions where a single new obstacle would trap the guard in a loop.
+pub fn part_2(map: &MapState) -> usize {
+    let mut loop_obstacles = 0;
+
+    let mut initial_map = map.clone();
+    initial_map.reset();
+    initial_map.tick_until_out_of_bounds_or_loop();
+    let check_coords = initial_map.visited_tiles.cells().collect::<Vec<_>>();
+
+    initial_map.reset();
+
+    for (x, y) in check_coords {
+        if initial_map.tiles[y][x].is_obstacle
+            || (initial_map.guard_pos.0 == x as i32 && initial_map.guard_pos.1 == y as i32)
+        {
+            continue;
+        }
+
+        // let mut cloned = initial_map.clone();
+        initial_map.tiles[y][x].is_obstacle = true;
+
+        if SimulCompleteResult::Loop == initial_map.tick_until_out_of_bounds_or_loop() {
+            loop_obstacles += 1;
+            // println!("Found loop obstacle on {x}, {y}");
+        }
+
+        initial_map.tiles[y][x].is_obstacle = false;
+        initial_map.reset();
+    }
+
+    loop_obstacles
+}
+
+const DIRECTIONS: [(i32, i32); 4] = [
+    // up
+    (0, -1),
+    // right
+    (1, 0),
+    // down
+    (0, 1),
+    // left
+    (-1, 0),
+];
+
+#[derive(Clone)]
+pub struct TileState {
+    pub is_obstacle: bool,
+}
+
+/// The lab map together with the guard simulation state.
+#[derive(Clone)]
+pub struct MapState {
+    pub start_guard_pos: (i32, i32),
+    pub tiles: Vec<Vec<TileState>>,
+    pub map_width: usize,
+    pub guard_pos: (i32, i32),
+    /// Index into the up, right, down, left directions.
+    pub guard_dir: usize,
+    /// Tiles the guard entered, with a layer per direction it was facing.
+    pub visited_tiles: BitGrid,
+}
+
+#[derive(PartialEq, Debug)]
+pub enum SimulCompleteResult {
+    OutOfBounds,
+    Loop,
+}
+
+pub fn parse_input(input: &str) -> MapState {
+    MapState::parse_from_input(input)
+}
+
+impl MapState {
+    pub fn parse_from_input(input: &str) -> Self {
+        let parsed = GridParser::new(|c| match c {
+            '.' | '^' => Some(TileState { is_obstacle: false }),
+            '#' => Some(TileState { is_obstacle: true }),
+            _ => None,
+        })
+        .marker('^', Count::ExactlyOne)
+        .parse(input)
+        .unwrap_or_else(|e| panic!("bad map: {e}"));
+
+        let width = parsed.grid.width();
+        assert!(width != 0);
+        let tiles = parsed.grid.rows().map(<[_]>::to_vec).collect::<Vec<_>>();
+
+        // init starting tile state
+        // we could probs do the tracking 1 tile behind to avoid this but whatever
+        let guard = parsed.one('^');
+        let guard_pos = (guard.x as i32, guard.y as i32);
+        let mut visited_map = BitGrid::with_layers(width, tiles.len(), DIRECTIONS.len());
+        visited_map.insert_layer(guard_pos.0 as usize, guard_pos.1 as usize, 0);
+
+        Self {
+            guard_pos,
+            start_guard_pos: guard_pos,
+            // facing up
+            guard_dir: 0,
+            tiles,
+            map_width: width,
+
+            visited_tiles: visited_map,
+        }
+    }
+
+    /// Moves or rotates the guard once.
+    ///
+    /// Returns whether the guard is still in bounds and whether it entered a tile in a direction it
+    /// already walked before, meaning it's looping.
+    #[inline]
+    pub fn tick(&mut self) -> (bool, bool) {
+        let dir = DIRECTIONS[self.guard_dir];
+        let next_pos_x = self.guard_pos.0 + dir.0;
+        let next_pos_y = self.guard_pos.1 + dir.1;
+
+        if !self.is_in_bounds(next_pos_x, next_pos_y) {
+            self.guard_pos = (next_pos_x, next_pos_y);
+            return (false, false);
+        }
+
+        let next_tile = &mut self.tiles[next_pos_y as usize][next_pos_x as usize];
+        if next_tile.is_obstacle {
+            // an obstacle was hit R O T A T E
+            self.rotate_guard();
+            return (true, false);
+        }
+
+        // dbg!(next_pos_x, next_pos_y);
+        self.guard_pos = (next_pos_x, next_pos_y);
+
+        // mark as visited, entering a tile facing the same way as before means we're looping
+        let is_looping = !self.visited_tiles.insert_layer(
+            next_pos_x as usize,
+            next_pos_y as usize,
+            self.guard_dir,
+        );
+
+        (true, is_looping)
+    }
+
+    pub fn tick_until_out_of_bounds_or_loop(&mut self) -> SimulCompleteResult {
+        loop {
+            common::cancel::checkpoint();
+            let (is_in_bounds, is_looping) = self.tick();
+
+            if is_in_bounds && is_looping {
+                // dbg!(self.guard_pos);
+                return SimulCompleteResult::Loop;
+            } else if !is_in_bounds {
+                return SimulCompleteResult::OutOfBounds;
+            }
+        }
+    }
+
+    fn rotate_guard(&mut self) {
+        self.guard_dir += 1;
+        if self.guard_dir >= DIRECTIONS.len() {
+            self.guard_dir = 0;
+        }
+    }
+
+    pub fn is_in_bounds(&self, x: i32, y: i32) -> bool {
+        if x < 0 || y < 0 || x >= self.map_width as i32 || y >= self.tiles.len() as i32 {
+            return false;
+        }
+
+        true
+    }
+
+    /// Puts the guard back on its starting tile and forgets all visited tiles.
+    pub fn reset(&mut self) {
+        self.visited_tiles.clear();
+        self.visited_tiles.insert_layer(
+            self.start_guard_pos.0 as usize,
+            self.start_guard_pos.1 as usize,
+            0,
+        );
+        self.guard_pos = self.start_guard_pos;
+        self.guard_dir = 0;
+    }
+}
@@ -0,0 +1,24 @@
+use std::time::Instant;
+
+use aoc_2024_day_06::{parse_input, part_1, part_2, INPUT};
+
+fn main() {
+    common::log::init_from_args();
+
+    let parsed_input = parse_input(&common::input::load(INPUT));
+
+    println!("part 1");
+    let started = Instant::now();
+    let unique_steps = part_1(&parsed_input);
+    let elapsed = started.elapsed();
+    println!("Unique steps: {unique_steps}");
+    println!("{elapsed:?}");
+
+    println!("=======");
+    println!("part 2");
+    let started = Instant::now();
+    let loop_obstacles = part_2(&parsed_input);
+    let elapsed = started.elapsed();
+    println!("Total loop obstacles: {loop_obstacles}");
+    println!("{elapsed:?}");
+}
@@ -0,0 +1,265 @@
+use std::{
+    fmt::{self, Display},
+    num::IntErrorKind,
+    str::FromStr,
+};
+
+#[cfg(feature = "bigint")]
+use common::{bigint::BigInt, debug};
+
+pub const INPUT: &str = include_str!("input.txt");
+
+pub const EXAMPLE_INPUT: &str = r#"
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+"#;
+
+/// Sums up the test values of equations that can be made true with `+` and `*`.
+pub fn part_1(parsed_input: &ParsedInput) -> Result<Value, String> {
+    let mut sum = Value::Small(0);
+    for equation in &parsed_input.equations {
+        if equation.is_part1_true()? {
+            sum = sum.checked_add(&equation.equation)?;
+        }
+    }
+    Ok(sum)
+}
+
+/// Sums up the test values of equations that can be made true with `+`, `*` and `||`.
+pub fn part_2(parsed_input: &ParsedInput) -> Result<Value, String> {
+    let mut sum = Value::Small(0);
+    for equation in &parsed_input.equations {
+        if equation.is_part2_true()? {
+            sum = sum.checked_add(&equation.equation)?;
+        }
+    }
+    Ok(sum)
+}
+
+/// A test value or a sum of them, kept in an `i128` until it doesn't fit one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Small(i128),
+    /// Only with the `bigint` feature, without it overflowing an `i128` is an error.
+    #[cfg(feature = "bigint")]
+    Big(BigInt),
+}
+
+impl Value {
+    #[cfg(feature = "bigint")]
+    fn to_big(&self) -> BigInt {
+        match self {
+            Value::Small(v) => BigInt::from(*v),
+            Value::Big(v) => v.clone(),
+        }
+    }
+
+    /// `self + rhs`, an error if it overflows an `i128` without the `bigint` feature.
+    pub fn checked_add(&self, rhs: &Value) -> Result<Value, String> {
+        match (self, rhs) {
+            (Value::Small(a), Value::Small(b)) => match a.checked_add(*b) {
+                Some(sum) => Ok(Value::Small(sum)),
+                None => Value::sum_overflowed(*a, *b),
+            },
+            #[cfg(feature = "bigint")]
+            (a, b) => Ok(Value::Big(a.to_big() + b.to_big())),
+        }
+    }
+
+    #[cfg(feature = "bigint")]
+    fn sum_overflowed(a: i128, b: i128) -> Result<Value, String> {
+        debug!("{a} + {b} overflows i128, summing with big integers");
+        Ok(Value::Big(BigInt::from(a) + BigInt::from(b)))
+    }
+
+    #[cfg(not(feature = "bigint"))]
+    fn sum_overflowed(a: i128, b: i128) -> Result<Value, String> {
+        Err(format!(
+            "{a} + {b} overflows i128, build with the bigint feature to solve it"
+        ))
+    }
+
+    #[cfg(feature = "bigint")]
+    fn parse_overflowed(s: &str) -> Result<Value, String> {
+        s.parse()
+            .map(Value::Big)
+            .map_err(|e| format!("bad test value {s:?}: {e}"))
+    }
+
+    #[cfg(not(feature = "bigint"))]
+    fn parse_overflowed(s: &str) -> Result<Value, String> {
+        Err(format!(
+            "test value {s} doesn't fit an i128, build with the bigint feature to solve it"
+        ))
+    }
+}
+
+impl FromStr for Value {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.parse::<i128>() {
+            Ok(v) => Ok(Value::Small(v)),
+            Err(e)
+                if matches!(
+                    e.kind(),
+                    IntErrorKind::PosOverflow | IntErrorKind::NegOverflow
+                ) =>
+            {
+                Value::parse_overflowed(s)
+            }
+            Err(e) => Err(format!("bad test value {s:?}: {e}")),
+        }
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Small(v) => v.fmt(f),
+            #[cfg(feature = "bigint")]
+            Value::Big(v) => v.fmt(f),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Equation {
+    /// The test value
+    pub equation: Value,
+    /// number and the number of base 10 digits it has
+    pub numbers: Vec<(i128, u32)>,
+}
+
+impl Equation {
+    pub fn is_part1_true(&self) -> Result<bool, String> {
+        self.is_true(false)
+    }
+
+    pub fn is_part2_true(&self) -> Result<bool, String> {
+        self.is_true(true)
+    }
+
+    /// Whether any mix of `+`, `*` and, if `concat` is set, `||` makes the numbers come out as
+    /// the test value, an error if that takes numbers past an `i128` without the `bigint` feature.
+    fn is_true(&self, concat: bool) -> Result<bool, String> {
+        match self.is_true_checked(concat) {
+            Some(is_true) => Ok(is_true),
+            None => self.is_true_overflowed(concat),
+        }
+    }
+
+    /// Like [`Equation::is_true`], `None` if an intermediate value doesn't fit an `i128`.
+    fn is_true_checked(&self, concat: bool) -> Option<bool> {
+        let target = match &self.equation {
+            Value::Small(v) => *v,
+            // No mix of numbers that fit an i128 reaches it without overflowing
+            #[cfg(feature = "bigint")]
+            Value::Big(_) => return None,
+        };
+
+        let mut last_frame: Vec<i128> = Vec::new();
+        for (number, num_digits) in &self.numbers {
+            if last_frame.is_empty() {
+                last_frame = vec![*number];
+                continue;
+            }
+
+            let mut next_frame = Vec::new();
+            for last in &last_frame {
+                next_frame.push(last.checked_add(*number)?);
+                next_frame.push(last.checked_mul(*number)?);
+                if concat {
+                    let concatenated = last
+                        .checked_mul(10i128.checked_pow(*num_digits)?)?
+                        .checked_add(*number)?;
+                    next_frame.push(concatenated);
+                }
+            }
+
+            last_frame = next_frame;
+        }
+
+        Some(last_frame.contains(&target))
+    }
+
+    /// [`Equation::is_true`] again with numbers that can't overflow.
+    #[cfg(feature = "bigint")]
+    fn is_true_overflowed(&self, concat: bool) -> Result<bool, String> {
+        debug!(
+            "{} overflows i128, retrying with big integers",
+            self.equation
+        );
+
+        let mut last_frame: Vec<BigInt> = Vec::new();
+        for (number, num_digits) in &self.numbers {
+            let number = BigInt::from(*number);
+            if last_frame.is_empty() {
+                last_frame = vec![number];
+                continue;
+            }
+
+            let mut next_frame = Vec::new();
+            for last in &last_frame {
+                next_frame.push(last + &number);
+                next_frame.push(last * &number);
+                if concat {
+                    next_frame.push(last * BigInt::from(10).pow(*num_digits) + &number);
+                }
+            }
+
+            last_frame = next_frame;
+        }
+
+        Ok(last_frame.contains(&self.equation.to_big()))
+    }
+
+    #[cfg(not(feature = "bigint"))]
+    fn is_true_overflowed(&self, _concat: bool) -> Result<bool, String> {
+        Err(format!(
+            "{} overflows i128, build with the bigint feature to solve it",
+            self.equation
+        ))
+    }
+}
+
+impl FromStr for Equation {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut equation_split = s.split(":");
+        let equation_num = equation_split.next().unwrap().parse()?;
+
+        let numbers_str = equation_split.next().unwrap().trim();
+        let numbers = numbers_str
+            .split(" ")
+            .map(|v| (v.parse::<i128>().unwrap(), v.len() as u32))
+            .collect::<Vec<_>>();
+
+        Ok(Self {
+            equation: equation_num,
+            numbers,
+        })
+    }
+}
+
+pub struct ParsedInput {
+    pub equations: Vec<Equation>,
+}
+
+pub fn parse_input(input: &str) -> ParsedInput {
+    let equations = input
+        .trim()
+        .lines()
+        .filter(|v| !v.is_empty())
+        .map(|v| v.parse().unwrap_or_else(|e| panic!("{e}")))
+        .collect::<Vec<Equation>>();
+    ParsedInput { equations }
+}
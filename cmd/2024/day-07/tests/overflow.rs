@@ -0,0 +1,61 @@
+use aoc_2024_day_07::{parse_input, part_1};
+
+/// Both test values add up to more than an i128 holds, the last one doesn't fit one on its own.
+const OVERFLOWING: &str = "\
+170141183460469231731687303715884105727: 170141183460469231731687303715884105727 1
+170141183460469231731687303715884105727: 170141183460469231731687303715884105727 1
+1701411834604692317316873037158841057270: 170141183460469231731687303715884105727 0
+12: 9 1 2";
+
+#[cfg(feature = "bigint")]
+#[test]
+fn sums_past_i128_with_big_integers() {
+    let parsed = parse_input(OVERFLOWING);
+    let expected = "340282366920938463463374607431768211466";
+    assert_eq!(
+        part_1(&parsed).map(|v| v.to_string()),
+        Ok(expected.to_string())
+    );
+
+    // The third only works by concatenating a 0
+    let with_concat = "2041694201525630780780247644590609268736";
+    assert_eq!(
+        aoc_2024_day_07::part_2(&parsed).map(|v| v.to_string()),
+        Ok(with_concat.to_string())
+    );
+}
+
+#[cfg(not(feature = "bigint"))]
+#[test]
+#[should_panic(expected = "build with the bigint feature")]
+fn test_values_past_i128_need_the_feature() {
+    parse_input(OVERFLOWING);
+}
+
+#[cfg(not(feature = "bigint"))]
+#[test]
+fn sums_past_i128_need_the_feature() {
+    let parsed = parse_input(
+        "170141183460469231731687303715884105727: 170141183460469231731687303715884105727\n\
+         1: 1",
+    );
+    assert_eq!(
+        part_1(&parsed),
+        Err(
+            "170141183460469231731687303715884105727 + 1 overflows i128, \
+             build with the bigint feature to solve it"
+                .to_string()
+        )
+    );
+}
+
+#[cfg(not(feature = "bigint"))]
+#[test]
+fn equations_past_i128_need_the_feature() {
+    // Multiplying the numbers overflows before the test value can be ruled out
+    let parsed = parse_input("5: 170141183460469231731687303715884105727 2");
+    assert_eq!(
+        part_1(&parsed),
+        Err("5 overflows i128, build with the bigint feature to solve it".to_string())
+    );
+}
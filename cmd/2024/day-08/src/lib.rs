@@ -1,7 +1,7 @@
-use std::collections::HashSet;
+use common::{hash::FastSet, math::gcd, trace};
 
-const INPUT: &str = include_str!("input.txt");
-const EXAMPLE_INPUT: &str = r#"
+pub const INPUT: &str = include_str!("input.txt");
+pub const EXAMPLE_INPUT: &str = r#"
 ............
 ........0...
 .....0......
@@ -16,23 +16,9 @@ const EXAMPLE_INPUT: &str = r#"
 ............
 "#;
 
-fn main() {
-    let use_example = std::env::args().any(|v| v == "--example");
-
-    println!("Launching, using example. {use_example}");
-
-    let parsed = parse_input(use_example.then_some(EXAMPLE_INPUT).unwrap_or(INPUT));
-
-    println!("part 1");
-    part_1(&parsed);
-
-    println!("=======");
-    println!("part 2");
-    part_2(&parsed);
-}
-
-fn part_1(parsed_input: &ParsedInput) {
-    let mut antinodes = HashSet::<(i32, i32)>::new();
+/// Counts the unique in-bounds antinodes mirrored across each antenna pair.
+pub fn part_1(parsed_input: &ParsedInput) -> usize {
+    let mut antinodes = FastSet::<(i32, i32)>::default();
     for (i, antenna) in parsed_input.antennas.iter().enumerate() {
         for (j, other_antenna) in parsed_input.antennas.iter().enumerate() {
             if i == j || other_antenna.frequency != antenna.frequency {
@@ -42,8 +28,8 @@ fn part_1(parsed_input: &ParsedInput) {
             let distance_x = other_antenna.x_pos as i32 - antenna.x_pos as i32;
             let distance_y = other_antenna.y_pos as i32 - antenna.y_pos as i32;
 
-            let antinode_pos_x = antenna.x_pos as i32 - distance_x as i32;
-            let antinode_pos_y = antenna.y_pos as i32 - distance_y as i32;
+            let antinode_pos_x = antenna.x_pos as i32 - distance_x;
+            let antinode_pos_y = antenna.y_pos as i32 - distance_y;
 
             if antinode_pos_x < 0
                 || antinode_pos_x >= parsed_input.width as i32
@@ -54,15 +40,19 @@ fn part_1(parsed_input: &ParsedInput) {
             }
 
             antinodes.insert((antinode_pos_x, antinode_pos_y));
-            println!("Antinode: {antinode_pos_x}, {antinode_pos_y}");
+            trace!("Antinode: {antinode_pos_x}, {antinode_pos_y}");
         }
     }
 
-    println!("Number of antinodes: {}", antinodes.len());
+    antinodes.len()
 }
 
-fn part_2(parsed_input: &ParsedInput) {
-    let mut antinodes = HashSet::<(i32, i32)>::new();
+/// Counts the unique in-bounds antinodes along the whole line of each antenna pair.
+///
+/// The line is walked from each antenna towards the other and beyond, in steps of the offset
+/// divided by its gcd so grid points between two antennas count as well.
+pub fn part_2(parsed_input: &ParsedInput) -> usize {
+    let mut antinodes = FastSet::<(i32, i32)>::default();
     for (i, antenna) in parsed_input.antennas.iter().enumerate() {
         for (j, other_antenna) in parsed_input.antennas.iter().enumerate() {
             if i == j || other_antenna.frequency != antenna.frequency {
@@ -71,6 +61,8 @@ fn part_2(parsed_input: &ParsedInput) {
 
             let distance_x = other_antenna.x_pos as i32 - antenna.x_pos as i32;
             let distance_y = other_antenna.y_pos as i32 - antenna.y_pos as i32;
+            let divisor = gcd(distance_x, distance_y);
+            let (step_x, step_y) = (distance_x / divisor, distance_y / divisor);
 
             let mut next_resonance_x = antenna.x_pos as i32;
             let mut next_resonance_y = antenna.y_pos as i32;
@@ -81,33 +73,34 @@ fn part_2(parsed_input: &ParsedInput) {
                 && next_resonance_y < parsed_input.height as i32
             {
                 antinodes.insert((next_resonance_x, next_resonance_y));
-                println!(
+                trace!(
                     "Antinode {}: {next_resonance_x}, {next_resonance_y}",
                     antenna.frequency
                 );
 
-                next_resonance_x -= distance_x;
-                next_resonance_y -= distance_y;
+                next_resonance_x += step_x;
+                next_resonance_y += step_y;
             }
         }
     }
 
-    println!("Number of antinodes: {}", antinodes.len());
+    antinodes.len()
 }
 
-struct Antenna {
-    x_pos: u32,
-    y_pos: u32,
-    frequency: char,
+pub struct Antenna {
+    pub x_pos: u32,
+    pub y_pos: u32,
+    pub frequency: char,
 }
 
-struct ParsedInput {
-    antennas: Vec<Antenna>,
-    width: usize,
-    height: usize,
+/// The antennas and the dimensions of the map they are on.
+pub struct ParsedInput {
+    pub antennas: Vec<Antenna>,
+    pub width: usize,
+    pub height: usize,
 }
 
-fn parse_input(input: &str) -> ParsedInput {
+pub fn parse_input(input: &str) -> ParsedInput {
     let mut antennas = Vec::new();
     let mut width = 0;
     let mut height = 0;
@@ -0,0 +1,25 @@
+use aoc_2024_day_08::{parse_input, part_1, part_2};
+
+/// Three antennas on one diagonal, two apart.
+const COLLINEAR: &str = "\
+a.........
+..........
+..a.......
+..........
+....a.....
+..........
+..........
+..........
+..........
+..........";
+
+#[test]
+fn part_1_uses_the_full_offset() {
+    // (0, 0), (4, 4) and (6, 6) from the antennas two apart, (8, 8) from the ones four apart
+    assert_eq!(part_1(&parse_input(COLLINEAR)), 4);
+}
+
+#[test]
+fn part_2_steps_through_points_between_antennas() {
+    assert_eq!(part_2(&parse_input(COLLINEAR)), 10);
+}
@@ -1,39 +1,26 @@
 use std::fmt::{Display, Write};
 
-const INPUT: &str = include_str!("input.txt");
-const EXAMPLE_INPUT: &str = r#"
+use common::debug;
+
+pub const INPUT: &str = include_str!("input.txt");
+pub const EXAMPLE_INPUT: &str = r#"
 12983712899287
 "#;
 // 00...111...2...333.44.5555.6666.777.888899
 
-fn main() {
-    let use_example = std::env::args().any(|v| v == "--example");
-
-    println!("Launching, using example. {use_example}");
-
-    let parsed = parse_input(use_example.then_some(EXAMPLE_INPUT).unwrap_or(INPUT));
-
-    println!("part 1");
-    part_1(&parsed);
-
-    println!("=======");
-    println!("part 2");
-    part_2(&parsed);
-}
-
-fn part_1(parsed_input: &ParsedInput) {
+/// Checksum after moving file blocks one at a time into the leftmost free space.
+pub fn part_1(parsed_input: &ParsedInput) -> u128 {
     let mut cloned_input = parsed_input.clone();
 
     // Compact
     while compact_step_part1(&mut cloned_input) {}
 
     // checksum
-    let result = checksum(&cloned_input);
-
-    println!("Result: {result}");
+    checksum(&cloned_input)
 }
 
-fn compact_step_part1(input: &mut ParsedInput) -> bool {
+/// Moves a single block from the end into the first free space, returns false once fully compacted.
+pub fn compact_step_part1(input: &mut ParsedInput) -> bool {
     let last_block_id = input.blocks.last().unwrap().id;
 
     let first_free_space = input
@@ -78,22 +65,22 @@ fn compact_step_part1(input: &mut ParsedInput) -> bool {
         new_last.free_space_right += free_space;
     }
 
-    return true;
+    true
 }
 
-fn part_2(parsed_input: &ParsedInput) {
+/// Checksum after moving whole files into the leftmost free space that fits them.
+pub fn part_2(parsed_input: &ParsedInput) -> u128 {
     let mut cloned_input = parsed_input.clone();
 
     // Compact
     compact_part2(&mut cloned_input);
 
     // checksum
-    let result = checksum(&cloned_input);
-
-    println!("Result: {result}");
+    checksum(&cloned_input)
 }
 
-fn compact_part2(input: &mut ParsedInput) {
+/// Moves every file, highest id first, into the leftmost free space that fits it.
+pub fn compact_part2(input: &mut ParsedInput) {
     let last_block = input.blocks.len();
 
     // println!("Original: {input}");
@@ -113,7 +100,6 @@ fn compact_part2(input: &mut ParsedInput) {
         };
 
         if free_index >= index {
-
             continue;
         }
 
@@ -126,7 +112,6 @@ fn compact_part2(input: &mut ParsedInput) {
         let old_left_free_space = input.blocks[free_index].free_space_right;
         input.blocks[free_index].free_space_right = 0;
 
-
         block.free_space_right = old_left_free_space - block.length;
         input.blocks.insert(free_index + 1, block);
         // println!("Step:     {input}");
@@ -135,7 +120,8 @@ fn compact_part2(input: &mut ParsedInput) {
     }
 }
 
-fn checksum(input: &ParsedInput) -> u128 {
+/// Sums up every block position multiplied by the id of the file in it.
+pub fn checksum(input: &ParsedInput) -> u128 {
     let mut result = 0;
     let mut position = 0;
     for block in &input.blocks {
@@ -152,29 +138,27 @@ fn checksum(input: &ParsedInput) -> u128 {
     result
 }
 
+/// A file on the disk followed by its free space.
 #[derive(Clone)]
-struct Block {
-    free_space_right: u32,
-    id: u32,
-    length: u32,
+pub struct Block {
+    pub free_space_right: u32,
+    pub id: u32,
+    pub length: u32,
 }
 
+/// The disk map, rendered as two characters per block with [`Display`].
 #[derive(Clone)]
-struct ParsedInput {
-    blocks: Vec<Block>,
+pub struct ParsedInput {
+    pub blocks: Vec<Block>,
 }
 
-fn parse_input(input: &str) -> ParsedInput {
-    println!("{}", input.trim().len());
+pub fn parse_input(input: &str) -> ParsedInput {
+    debug!("input length: {}", input.trim().len());
     let mut iter = input.trim().chars();
 
     let mut blocks = Vec::new();
     let mut id = 0u32;
-    loop {
-        let Some(length_char) = iter.next() else {
-            break;
-        };
-
+    while let Some(length_char) = iter.next() {
         let length = length_char.to_digit(10).unwrap();
         assert!(length > 0);
 
@@ -196,7 +180,7 @@ impl Display for ParsedInput {
         for block in &self.blocks {
             for _ in 0..block.length {
                 let mut s = block.id.to_string();
-                if s.len() < 2{
+                if s.len() < 2 {
                     s = format!("0{s}");
                 }
                 f.write_str(&s)?;
@@ -0,0 +1,7 @@
+use aoc_2024_day_09::{parse_input, EXAMPLE_INPUT};
+use common::assert_snapshot;
+
+#[test]
+fn example_disk_map() {
+    assert_snapshot!("example_disk_map", parse_input(EXAMPLE_INPUT).to_string());
+}
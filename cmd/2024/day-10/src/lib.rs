@@ -0,0 +1,137 @@
+use std::fmt::{Display, Write};
+
+use common::{
+    debug,
+    search::{bfs, count_paths},
+    trace,
+};
+
+pub const INPUT: &str = include_str!("input.txt");
+pub const EXAMPLE_INPUT: &str = r#"
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732
+"#;
+
+/// Sums up the number of distinct peaks reachable from every trail head.
+pub fn part_1(parsed_input: &ParsedInput) -> usize {
+    let trail_heads = parsed_input.positions_at(0);
+    debug!("Trail heads: {}", trail_heads.len());
+
+    trail_heads
+        .into_iter()
+        .map(|trail_head| {
+            let reachable = bfs([trail_head], |v| parsed_input.uphill(v));
+            let score = reachable
+                .order()
+                .iter()
+                .filter(|v| parsed_input.height_at(**v) == 9)
+                .count();
+            trace!("Trail head: {trail_head}: {score}");
+            score
+        })
+        .sum()
+}
+
+/// Sums up the number of distinct trails starting at every trail head.
+///
+/// Every step goes up by one, so the trails form a DAG and counting them is a single pass.
+pub fn part_2(parsed_input: &ParsedInput) -> u64 {
+    let trail_heads = parsed_input.positions_at(0);
+    debug!("Trail heads: {}", trail_heads.len());
+
+    count_paths(trail_heads, |v| parsed_input.uphill(v))
+        .into_iter()
+        .filter(|(v, _)| parsed_input.height_at(*v) == 9)
+        .map(|(_, count)| count)
+        .sum()
+}
+
+#[derive(Clone, Copy, Hash, Debug, Eq, PartialEq)]
+pub struct Vec2 {
+    pub x: u8,
+    pub y: u8,
+}
+
+impl Display for Vec2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_char('(')?;
+        self.x.fmt(f)?;
+        f.write_char(',')?;
+        self.y.fmt(f)?;
+        f.write_char(')')
+    }
+}
+
+const DIRECTIONS: [(i8, i8); 4] = [
+    (1, 0),  // Right
+    (0, 1),  // Down
+    (-1, 0), // Left
+    (0, -1), // Up
+];
+
+/// The topographic map, one height per tile.
+pub struct ParsedInput {
+    pub height_map: Vec<Vec<u32>>,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl ParsedInput {
+    pub fn height_at(&self, pos: Vec2) -> u32 {
+        self.height_map[pos.y as usize][pos.x as usize]
+    }
+
+    /// Every tile of the given height.
+    pub fn positions_at(&self, height: u32) -> Vec<Vec2> {
+        let mut positions = Vec::new();
+        for (y, row) in self.height_map.iter().enumerate() {
+            for (x, h) in row.iter().enumerate() {
+                if *h == height {
+                    positions.push(Vec2 {
+                        x: x as u8,
+                        y: y as u8,
+                    });
+                }
+            }
+        }
+        positions
+    }
+
+    /// The neighbouring tiles exactly one higher than `pos`.
+    pub fn uphill(&self, pos: Vec2) -> impl Iterator<Item = Vec2> + '_ {
+        let height = self.height_at(pos);
+        DIRECTIONS.iter().filter_map(move |(x_dir, y_dir)| {
+            let x = pos.x.checked_add_signed(*x_dir)?;
+            let y = pos.y.checked_add_signed(*y_dir)?;
+            if x as usize >= self.width || y as usize >= self.height {
+                return None;
+            }
+
+            let next = Vec2 { x, y };
+            (self.height_at(next) == height + 1).then_some(next)
+        })
+    }
+}
+
+pub fn parse_input(input: &str) -> ParsedInput {
+    let mut height_map = Vec::new();
+    for row in input.trim().lines() {
+        height_map.push(
+            row.chars()
+                .map(|c| c.to_digit(10).unwrap())
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    ParsedInput {
+        width: height_map[0].len(),
+        height: height_map.len(),
+        height_map,
+    }
+}
@@ -0,0 +1,158 @@
+#[cfg(feature = "bigint")]
+use common::{bigint::BigUint, debug};
+
+pub const INPUT: &str = include_str!("input.txt");
+pub const EXAMPLE_INPUT: &str = r#"125 17"#;
+
+/// Number of stones after blinking 25 times.
+pub fn part_1(parsed_input: &ParsedInput) -> Result<u64, String> {
+    run_recursive(parsed_input, 25)
+}
+
+/// Number of stones after blinking 75 times.
+pub fn part_2(parsed_input: &ParsedInput) -> Result<u64, String> {
+    run_recursive(parsed_input, 75)
+}
+
+/// Number of stones the input turns into after blinking `iterations` times, an error if a
+/// number overflows a `u64`.
+pub fn run_recursive(input: &ParsedInput, iterations: u32) -> Result<u64, String> {
+    if iterations == 0 {
+        return Ok(input.len() as u64);
+    }
+    let mut solver = Box::new(Solver::new(iterations as usize));
+
+    let mut len = 0u64;
+    for start in input {
+        let stones = solver.find_len_recursive(u64::from(*start), iterations - 1)?;
+        len = len.checked_add(stones).ok_or_else(count_overflowed)?;
+    }
+
+    Ok(len)
+}
+
+/// The error for more stones than a `u64` counts, which not even big integers help with.
+fn count_overflowed() -> String {
+    "the number of stones overflows u64".to_string()
+}
+
+/// The numbers engraved on the initial stones.
+pub type ParsedInput = Vec<u32>;
+
+pub fn parse_input(input: &str) -> ParsedInput {
+    let mut output = Vec::new();
+
+    for num_str in input.split(' ') {
+        output.push(num_str.parse().unwrap());
+    }
+
+    output
+}
+
+/// Memoizes the number of stones a small stone turns into per remaining depth.
+pub struct Solver {
+    cache: Vec<Vec<Option<u64>>>,
+}
+
+impl Solver {
+    pub fn new(iterations: usize) -> Self {
+        Self {
+            cache: vec![vec![None; iterations]; 2024],
+        }
+    }
+
+    /// Number of stones `input` turns into after `remaining_depth + 1` blinks.
+    pub fn find_len_recursive(&mut self, input: u64, remaining_depth: u32) -> Result<u64, String> {
+        if (input as usize) < self.cache.len() {
+            if let Some(cached_result) = self.cache[input as usize][remaining_depth as usize] {
+                return Ok(cached_result);
+            }
+        }
+
+        let Some((entry_a, entry_b)) = Self::step(input) else {
+            return self.find_len_overflowed(input, remaining_depth);
+        };
+        let mut this_len = 0u64;
+
+        if remaining_depth > 0 {
+            for entry in std::iter::once(entry_a).chain(entry_b) {
+                let len = self.find_len_recursive(entry, remaining_depth - 1)?;
+                this_len = this_len.checked_add(len).ok_or_else(count_overflowed)?;
+            }
+        } else {
+            this_len = 1;
+            if entry_b.is_some() {
+                this_len += 1;
+            }
+        }
+
+        if (input as usize) < self.cache.len() {
+            self.cache[input as usize][remaining_depth as usize] = Some(this_len);
+        }
+
+        Ok(this_len)
+    }
+
+    /// [`Solver::find_len_recursive`] for a stone whose next number is too big for a `u64`.
+    #[cfg(feature = "bigint")]
+    fn find_len_overflowed(&mut self, input: u64, remaining_depth: u32) -> Result<u64, String> {
+        debug!("stone {input} outgrows u64, continuing with big integers");
+        if remaining_depth == 0 {
+            return Ok(1);
+        }
+        self.find_len_big(
+            BigUint::from(input) * BigUint::from(2024u32),
+            remaining_depth - 1,
+        )
+    }
+
+    #[cfg(not(feature = "bigint"))]
+    fn find_len_overflowed(&mut self, input: u64, _remaining_depth: u32) -> Result<u64, String> {
+        Err(format!(
+            "stone {input} times 2024 overflows u64, build with the bigint feature to solve it"
+        ))
+    }
+
+    /// Like [`Solver::find_len_recursive`], handing the stones back to it once they split small
+    /// enough to fit a `u64` again.
+    #[cfg(feature = "bigint")]
+    fn find_len_big(&mut self, input: BigUint, remaining_depth: u32) -> Result<u64, String> {
+        if let Some(small) = input.to_u64() {
+            return self.find_len_recursive(small, remaining_depth);
+        }
+
+        let s = input.to_string();
+        let entries = if s.len().is_multiple_of(2) {
+            let (first, second) = s.split_at(s.len() / 2);
+            vec![first.parse().unwrap(), second.parse().unwrap()]
+        } else {
+            vec![input * BigUint::from(2024u32)]
+        };
+
+        if remaining_depth == 0 {
+            return Ok(entries.len() as u64);
+        }
+        entries.into_iter().try_fold(0u64, |len, v| {
+            let stones = self.find_len_big(v, remaining_depth - 1)?;
+            len.checked_add(stones).ok_or_else(count_overflowed)
+        })
+    }
+
+    /// Applies the blink rules to a single stone, returns the second stone if it split. `None` if
+    /// the new number doesn't fit a `u64`.
+    pub fn step(entry: u64) -> Option<(u64, Option<u64>)> {
+        if entry == 0 {
+            return Some((1, None));
+        }
+
+        let s = entry.to_string();
+        if s.len().is_multiple_of(2) {
+            let half = s.len() / 2;
+            let (first, second) = s.split_at(half);
+
+            Some((first.parse().unwrap(), Some(second.parse().unwrap())))
+        } else {
+            Some((entry.checked_mul(2024)?, None))
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use std::time::Instant;
+
+use aoc_2024_day_11::{parse_input, part_1, part_2, EXAMPLE_INPUT, INPUT};
+
+fn main() {
+    common::log::init_from_args();
+
+    let use_example = std::env::args().any(|v| v == "--example");
+
+    println!("Launching, using example. {use_example}");
+
+    let input = common::input::load(if use_example { EXAMPLE_INPUT } else { INPUT });
+    let parsed = parse_input(&input);
+
+    println!("part 1");
+    match part_1(&parsed) {
+        Ok(len) => println!("Length: {len}"),
+        Err(err) => println!("Failed: {err}"),
+    }
+
+    println!("=======");
+    println!("part 2");
+    let started = Instant::now();
+    let len = part_2(&parsed);
+    let elapsed = started.elapsed();
+    match len {
+        Ok(len) => println!("Length: {len}"),
+        Err(err) => println!("Failed: {err}"),
+    }
+    println!("{elapsed:?}");
+}
@@ -0,0 +1,32 @@
+use aoc_2024_day_11::{parse_input, part_1, run_recursive, Solver, EXAMPLE_INPUT};
+
+/// 19 digits, so it gets multiplied by 2024 rather than split, which doesn't fit a u64.
+const OVERFLOWING: u64 = 9_999_999_999_999_999_999;
+
+#[test]
+fn example_stone_counts() {
+    let parsed = parse_input(EXAMPLE_INPUT);
+    assert_eq!(run_recursive(&parsed, 0), Ok(2));
+    assert_eq!(run_recursive(&parsed, 6), Ok(22));
+    assert_eq!(part_1(&parsed), Ok(55312));
+}
+
+#[cfg(not(feature = "bigint"))]
+#[test]
+fn stones_past_u64_need_the_feature() {
+    assert_eq!(
+        Solver::new(2).find_len_recursive(OVERFLOWING, 1),
+        Err(format!(
+            "stone {OVERFLOWING} times 2024 overflows u64, build with the bigint feature to solve it"
+        ))
+    );
+}
+
+#[cfg(feature = "bigint")]
+#[test]
+fn stones_past_u64_with_big_integers() {
+    // 23 digits after the first blink, so it doesn't split on the second either
+    assert_eq!(Solver::new(2).find_len_recursive(OVERFLOWING, 1), Ok(1));
+    // 26 digits after the second, which splits into two stones on the third
+    assert_eq!(Solver::new(3).find_len_recursive(OVERFLOWING, 2), Ok(2));
+}
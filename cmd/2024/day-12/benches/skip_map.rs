@@ -0,0 +1,18 @@
+//! Part 2 with the skip map as a SipHash set, an Fx set and the bit grid it actually uses.
+
+use std::collections::hash_map::RandomState;
+
+use aoc_2024_day_12::{parse_input, part_2, part_2_hashed, INPUT};
+use common::{bench::bench, hash::FxBuildHasher};
+
+fn main() {
+    let parsed = parse_input(INPUT);
+
+    bench("HashSet (SipHash)", 20, || {
+        part_2_hashed::<RandomState>(&parsed)
+    });
+    bench("FastSet (Fx)", 20, || {
+        part_2_hashed::<FxBuildHasher>(&parsed)
+    });
+    bench("BitGrid", 20, || part_2(&parsed));
+}
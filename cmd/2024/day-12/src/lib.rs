@@ -1,7 +1,9 @@
-use std::{collections::HashSet, ops::ControlFlow};
+use std::{collections::HashSet, fmt::Write, hash::BuildHasher, ops::ControlFlow};
 
-const INPUT: &str = include_str!("input.txt");
-const EXAMPLE_INPUT: &str = r#"
+use common::{bits::BitGrid, debug, trace};
+
+pub const INPUT: &str = include_str!("input.txt");
+pub const EXAMPLE_INPUT: &str = r#"
 RRRRIICCFF
 RRRRIICCCF
 VVRRRCCFFF
@@ -14,21 +16,6 @@ MIIISIJEEE
 MMMISSJEEE
 "#;
 
-fn main() {
-    let use_example = std::env::args().any(|v| v == "--example");
-
-    println!("Launching, using example. {use_example}");
-
-    let parsed = parse_input(use_example.then_some(EXAMPLE_INPUT).unwrap_or(INPUT));
-
-    println!("part 1");
-    part_1(&parsed);
-
-    println!("=======");
-    println!("part 2");
-    part_2(&parsed);
-}
-
 const NEIGHBORS: [(i32, i32); 4] = [
     // aa
     (1, 0),
@@ -42,7 +29,8 @@ const EDGE_DOWN: usize = 1;
 const EDGE_LEFT: usize = 2;
 const EDGE_UP: usize = 3;
 
-fn part_1(parsed_input: &ParsedInput) {
+/// Sums up area times perimeter of every region.
+pub fn part_1(parsed_input: &ParsedInput) -> u32 {
     let (region_map, highest_id) = find_regions(parsed_input);
 
     let mut sum = 0;
@@ -76,26 +64,75 @@ fn part_1(parsed_input: &ParsedInput) {
 
         let this_sum = area * fences;
         sum += this_sum;
-        println!("{area}a * {fences}f = {this_sum}")
+        debug!("{area}a * {fences}f = {this_sum}")
     }
 
-    println!("sum: {sum}");
+    sum
+}
+
+/// Sums up area times number of straight fence sides of every region.
+pub fn part_2(parsed_input: &ParsedInput) -> u32 {
+    let mut skip_map = BitGrid::with_layers(parsed_input[0].len(), parsed_input.len(), 4);
+    part_2_with(parsed_input, &mut skip_map)
+}
+
+/// [`part_2`] with the counted edges in a hash set using the hasher `S` instead of a
+/// [`BitGrid`], so benches/skip_map.rs can compare hashers on the same traffic.
+#[doc(hidden)]
+pub fn part_2_hashed<S: BuildHasher + Default>(parsed_input: &ParsedInput) -> u32 {
+    part_2_with(parsed_input, &mut HashSet::with_hasher(S::default()))
+}
+
+/// Edges already counted as part of a straight fence, cleared for every region.
+trait SkipMap {
+    fn contains_edge(&self, x: usize, y: usize, edge: usize) -> bool;
+    fn insert_edge(&mut self, x: usize, y: usize, edge: usize);
+    fn clear_edges(&mut self);
 }
 
-fn part_2(parsed_input: &ParsedInput) {
+impl SkipMap for BitGrid {
+    fn contains_edge(&self, x: usize, y: usize, edge: usize) -> bool {
+        self.contains_layer(x, y, edge)
+    }
+
+    fn insert_edge(&mut self, x: usize, y: usize, edge: usize) {
+        self.insert_layer(x, y, edge);
+    }
+
+    fn clear_edges(&mut self) {
+        self.clear();
+    }
+}
+
+impl<S: BuildHasher> SkipMap for HashSet<(usize, usize, usize), S> {
+    fn contains_edge(&self, x: usize, y: usize, edge: usize) -> bool {
+        self.contains(&(x, y, edge))
+    }
+
+    fn insert_edge(&mut self, x: usize, y: usize, edge: usize) {
+        self.insert((x, y, edge));
+    }
+
+    fn clear_edges(&mut self) {
+        self.clear();
+    }
+}
+
+/// [`part_2`] keeping the counted edges in `skip_map`.
+fn part_2_with(parsed_input: &ParsedInput, skip_map: &mut impl SkipMap) -> u32 {
     let (region_map, highest_id) = find_regions(parsed_input);
 
     let mut sum = 0;
 
     for id in 0..highest_id {
+        skip_map.clear_edges();
+
         let mut area = 0;
         let mut fences = 0;
 
         let height = parsed_input.len();
         let width = parsed_input[0].len();
 
-        let mut skip_map = HashSet::new();
-
         for y in 0..height {
             for x in 0..width {
                 if region_map[y][x] == id {
@@ -103,68 +140,28 @@ fn part_2(parsed_input: &ParsedInput) {
                     area += 1;
 
                     let edges = get_edge_directions(&region_map, x as u32, y as u32, id);
-                    if edges[EDGE_UP] {
-                        if !skip_map.contains(&(x, y, EDGE_UP)) {
-                            extend_fence_right(
-                                x,
-                                y,
-                                width,
-                                id,
-                                &region_map,
-                                &mut skip_map,
-                                EDGE_UP,
-                            );
+                    if edges[EDGE_UP] && !skip_map.contains_edge(x, y, EDGE_UP) {
+                        extend_fence_right(x, y, width, id, &region_map, skip_map, EDGE_UP);
 
-                            fences += 1;
-                        }
+                        fences += 1;
                     }
 
-                    if edges[EDGE_DOWN] {
-                        if !skip_map.contains(&(x, y, EDGE_DOWN)) {
-                            extend_fence_right(
-                                x,
-                                y,
-                                width,
-                                id,
-                                &region_map,
-                                &mut skip_map,
-                                EDGE_DOWN,
-                            );
+                    if edges[EDGE_DOWN] && !skip_map.contains_edge(x, y, EDGE_DOWN) {
+                        extend_fence_right(x, y, width, id, &region_map, skip_map, EDGE_DOWN);
 
-                            fences += 1;
-                        }
+                        fences += 1;
                     }
 
-                    if edges[EDGE_LEFT] {
-                        if !skip_map.contains(&(x, y, EDGE_LEFT)) {
-                            extend_fence_down(
-                                x,
-                                y,
-                                height,
-                                id,
-                                &region_map,
-                                &mut skip_map,
-                                EDGE_LEFT,
-                            );
+                    if edges[EDGE_LEFT] && !skip_map.contains_edge(x, y, EDGE_LEFT) {
+                        extend_fence_down(x, y, height, id, &region_map, skip_map, EDGE_LEFT);
 
-                            fences += 1;
-                        }
+                        fences += 1;
                     }
 
-                    if edges[EDGE_RIGHT] {
-                        if !skip_map.contains(&(x, y, EDGE_RIGHT)) {
-                            extend_fence_down(
-                                x,
-                                y,
-                                height,
-                                id,
-                                &region_map,
-                                &mut skip_map,
-                                EDGE_RIGHT,
-                            );
+                    if edges[EDGE_RIGHT] && !skip_map.contains_edge(x, y, EDGE_RIGHT) {
+                        extend_fence_down(x, y, height, id, &region_map, skip_map, EDGE_RIGHT);
 
-                            fences += 1;
-                        }
+                        fences += 1;
                     }
                 }
             }
@@ -172,10 +169,10 @@ fn part_2(parsed_input: &ParsedInput) {
 
         let this_sum = area * fences;
         sum += this_sum;
-        println!("{id}: {area}a * {fences}f = {this_sum}")
+        debug!("{id}: {area}a * {fences}f = {this_sum}")
     }
 
-    println!("sum: {sum}");
+    sum
 }
 
 fn extend_fence_right(
@@ -183,8 +180,8 @@ fn extend_fence_right(
     y: usize,
     width: usize,
     region_id: u32,
-    region_map: &Vec<Vec<u32>>,
-    skip_map: &mut HashSet<(usize, usize, usize)>,
+    region_map: &[Vec<u32>],
+    skip_map: &mut impl SkipMap,
     edge: usize,
 ) {
     for inner_x in x..width {
@@ -193,7 +190,7 @@ fn extend_fence_right(
         {
             break;
         }
-        println!("{region_id}: Extended fence right {inner_x}.{y}")
+        trace!("{region_id}: Extended fence right {inner_x}.{y}")
     }
 }
 
@@ -202,8 +199,8 @@ fn extend_fence_down(
     y: usize,
     height: usize,
     region_id: u32,
-    region_map: &Vec<Vec<u32>>,
-    skip_map: &mut HashSet<(usize, usize, usize)>,
+    region_map: &[Vec<u32>],
+    skip_map: &mut impl SkipMap,
     edge: usize,
 ) {
     for inner_y in y..height {
@@ -213,19 +210,19 @@ fn extend_fence_down(
             break;
         }
 
-        println!("{region_id}: Extended fence down {x}.{inner_y}")
+        trace!("{region_id}: Extended fence down {x}.{inner_y}")
     }
 }
 
 fn extend_step(
-    skip_map: &mut HashSet<(usize, usize, usize)>,
+    skip_map: &mut impl SkipMap,
     x: usize,
     y: usize,
     edge: usize,
-    region_map: &Vec<Vec<u32>>,
+    region_map: &[Vec<u32>],
     region_id: u32,
 ) -> ControlFlow<()> {
-    if skip_map.contains(&(x, y, edge)) {
+    if skip_map.contains_edge(x, y, edge) {
         return ControlFlow::Break(());
     }
 
@@ -235,7 +232,7 @@ fn extend_step(
 
     let inner_edges = get_edge_directions(region_map, x as u32, y as u32, region_id);
     if inner_edges[edge] {
-        skip_map.insert((x, y, edge));
+        skip_map.insert_edge(x, y, edge);
     } else {
         return ControlFlow::Break(());
     }
@@ -243,14 +240,14 @@ fn extend_step(
     ControlFlow::Continue(())
 }
 
-fn get_edge_directions(region_map: &Vec<Vec<u32>>, x: u32, y: u32, region: u32) -> [bool; 4] {
+fn get_edge_directions(region_map: &[Vec<u32>], x: u32, y: u32, region: u32) -> [bool; 4] {
     let mut edges = [false; 4];
 
     for (i, (dir_x, dir_y)) in NEIGHBORS.iter().enumerate() {
         let new_pos_x = x as i32 + dir_x;
         let new_pos_y = y as i32 + dir_y;
 
-        if !is_region_checked(&region_map, new_pos_x, new_pos_y, region) {
+        if !is_region_checked(region_map, new_pos_x, new_pos_y, region) {
             edges[i] = true;
         }
     }
@@ -258,7 +255,8 @@ fn get_edge_directions(region_map: &Vec<Vec<u32>>, x: u32, y: u32, region: u32)
     edges
 }
 
-fn is_region_checked(region_map: &Vec<Vec<u32>>, x: i32, y: i32, region: u32) -> bool {
+/// Whether the position is in bounds and belongs to the given region.
+pub fn is_region_checked(region_map: &[Vec<u32>], x: i32, y: i32, region: u32) -> bool {
     let height = region_map.len();
     let width = region_map[0].len();
 
@@ -269,9 +267,16 @@ fn is_region_checked(region_map: &Vec<Vec<u32>>, x: i32, y: i32, region: u32) ->
     region_map[y as usize][x as usize] == region
 }
 
-fn find_regions(parsed_input: &ParsedInput) -> (Vec<Vec<u32>>, u32) {
+/// Region id of every tile, indexed by `[y][x]`.
+pub type RegionMap = Vec<Vec<u32>>;
+
+/// Splits the garden into connected regions of the same plant, returns the region map and the
+/// number of region ids handed out.
+///
+/// Merged regions leave gaps, so not every id below the returned count is in use.
+pub fn find_regions(parsed_input: &ParsedInput) -> (RegionMap, u32) {
     let mut region_id_gen = 0;
-    let mut region_map: Vec<Vec<u32>> = Vec::new();
+    let mut region_map: RegionMap = Vec::new();
 
     let height = parsed_input.len();
     let width = parsed_input[0].len();
@@ -317,38 +322,45 @@ fn find_regions(parsed_input: &ParsedInput) -> (Vec<Vec<u32>>, u32) {
         }
     }
 
-    for row in &region_map {
+    trace!("regions:\n{}", render_regions(&region_map));
+
+    (region_map, region_id_gen)
+}
+
+/// The region id of every plot, right aligned in columns of three.
+pub fn render_regions(region_map: &[Vec<u32>]) -> String {
+    let mut out = String::new();
+    for row in region_map {
         for region in row {
-            print!("{:3}", region)
+            write!(out, "{:3}", region).unwrap();
         }
-        println!("");
+        out.push('\n');
     }
-
-    (region_map, region_id_gen)
+    out
 }
 
 // Merges region_b into region_a
-fn merge_regions(region_map: &mut Vec<Vec<u32>>, region_a: u32, region_b: u32) {
-    let height = region_map.len();
+fn merge_regions(region_map: &mut [Vec<u32>], region_a: u32, region_b: u32) {
     let width = region_map[0].len();
 
-    for y in 0..height {
-        for x in 0..width {
-            if x >= region_map[y].len() {
-                // We are done
-                return;
+    for row in region_map.iter_mut() {
+        for region in row.iter_mut() {
+            if *region == region_b {
+                *region = region_a;
             }
+        }
 
-            if region_map[y][x] == region_b {
-                region_map[y][x] = region_a;
-            }
+        if row.len() < width {
+            // We are done
+            return;
         }
     }
 }
 
-type ParsedInput = Vec<Vec<char>>;
+/// The garden plots as rows of plant types.
+pub type ParsedInput = Vec<Vec<char>>;
 
-fn parse_input(input: &str) -> ParsedInput {
+pub fn parse_input(input: &str) -> ParsedInput {
     let mut output = Vec::new();
 
     for line in input.trim().lines() {
@@ -0,0 +1,8 @@
+use aoc_2024_day_12::{find_regions, parse_input, render_regions, EXAMPLE_INPUT};
+use common::assert_snapshot;
+
+#[test]
+fn example_regions() {
+    let (region_map, _) = find_regions(&parse_input(EXAMPLE_INPUT));
+    assert_snapshot!("example_regions", render_regions(&region_map));
+}
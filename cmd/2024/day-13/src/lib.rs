@@ -0,0 +1,350 @@
+use std::num::TryFromIntError;
+
+#[cfg(feature = "bigint")]
+use common::bigint::BigInt;
+use common::{
+    geometry::{cross, Line},
+    math::extended_gcd,
+    rational::Rational,
+    trace, IVec2,
+};
+
+pub const INPUT: &str = include_str!("input.txt");
+pub const EXAMPLE_INPUT: &str = r#"
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279
+"#;
+
+/// Fewest tokens needed to win every winnable prize.
+pub fn part_1(parsed_input: &ParsedInput) -> Result<Tokens, String> {
+    total_tokens(parsed_input, false)
+}
+
+/// Like [`part_1`], with the prizes moved 10000000000000 further away on both axes.
+pub fn part_2(parsed_input: &ParsedInput) -> Result<Tokens, String> {
+    total_tokens(parsed_input, true)
+}
+
+/// How much further away the prizes are in part 2, on both axes.
+const PART_2_OFFSET: u64 = 10_000_000_000_000;
+
+/// Token counts, big integers with the `bigint` feature so far away prizes can't overflow them.
+#[cfg(not(feature = "bigint"))]
+pub type Tokens = u64;
+#[cfg(feature = "bigint")]
+pub type Tokens = BigInt;
+
+/// A press of A costs 3 tokens, a press of B 1.
+#[cfg(not(feature = "bigint"))]
+fn total_tokens(parsed_input: &ParsedInput, is_part_2: bool) -> Result<Tokens, String> {
+    let mut total: Tokens = 0;
+    for item in parsed_input {
+        if let Some((a, b)) = solve(item, is_part_2)? {
+            total = a
+                .checked_mul(3)
+                .and_then(|v| v.checked_add(b))
+                .and_then(|v| v.checked_add(total))
+                .ok_or_else(|| overflowed("the token count"))?;
+        }
+    }
+
+    Ok(total)
+}
+
+#[cfg(feature = "bigint")]
+fn total_tokens(parsed_input: &ParsedInput, is_part_2: bool) -> Result<Tokens, String> {
+    let mut total = BigInt::zero();
+    for item in parsed_input {
+        if let Some((a, b)) = solve_big(item, is_part_2)? {
+            total += a * BigInt::from(3);
+            total += b;
+        }
+    }
+
+    Ok(total)
+}
+
+/// The error for numbers that don't fit the integers [`solve`] works with.
+fn overflowed(what: &str) -> String {
+    format!("{what} overflows, build with the bigint feature to solve it")
+}
+
+/// `value` as an [`IVec2`], or the error for it not fitting one.
+fn to_ivec2(value: UVec2, what: &str) -> Result<IVec2, String> {
+    IVec2::try_from(value).map_err(|_| overflowed(what))
+}
+
+/// Finds the cheapest number of A and B button presses that reach the prize, if there are any.
+///
+/// The A presses walk along a line from the origin and the B presses along a line ending at the
+/// prize, so where those cross is the only mix of presses that can work. It only counts if both
+/// press counts come out as whole, non-negative numbers. Buttons moving the same way don't give a
+/// crossing, see [`solve_collinear`] for those.
+///
+/// `Err` when the numbers are too big for the line geometry, `Ok(None)` when the prize can't be
+/// won.
+pub fn solve(setup: &Setup, is_part_2: bool) -> Result<Option<(u64, u64)>, String> {
+    let mut prize = setup.prize;
+    if is_part_2 {
+        let far = |v: u64| {
+            v.checked_add(PART_2_OFFSET)
+                .ok_or_else(|| overflowed("the prize"))
+        };
+        prize = UVec2 {
+            x: far(prize.x)?,
+            y: far(prize.y)?,
+        };
+    }
+
+    let button_a = to_ivec2(setup.button_a, "button A")?;
+    let button_b = to_ivec2(setup.button_b, "button B")?;
+    let prize = to_ivec2(prize, "the prize")?;
+    if button_a == IVec2::default()
+        || button_b == IVec2::default()
+        || cross(IVec2::default(), button_a, button_b) == 0
+    {
+        return Ok(solve_collinear(button_a, button_b, prize));
+    }
+    if !fits_geometry(button_a, button_b, prize) {
+        return Err(overflowed("the line crossing"));
+    }
+
+    let a_line = Line::through(IVec2::default(), button_a);
+    let b_line = Line::through(prize, button_b);
+    let Some(crossing) = a_line.intersection(&b_line) else {
+        return Ok(None);
+    };
+
+    // A button moving straight up or down says nothing about its presses along x
+    let a_presses = if button_a.x != 0 {
+        crossing.x / button_a.x.into()
+    } else {
+        crossing.y / button_a.y.into()
+    };
+    let b_presses = if button_b.x != 0 {
+        (Rational::from(prize.x) - crossing.x) / button_b.x.into()
+    } else {
+        (Rational::from(prize.y) - crossing.y) / button_b.y.into()
+    };
+    trace!("presses: {a_presses} {b_presses}");
+
+    let presses = |v: Rational| u64::try_from(v.to_integer()?).ok();
+    Ok(presses(a_presses).zip(presses(b_presses)))
+}
+
+/// Whether [`solve`] can cross the lines for these numbers without overflowing.
+///
+/// The point past the prize has to fit an `i64`, and the crossing and press counts are fractions
+/// with numerators and denominators of up to twice the largest number times the largest button
+/// number squared, which have to fit an `i128`.
+fn fits_geometry(button_a: IVec2, button_b: IVec2, prize: IVec2) -> bool {
+    let largest = |v: IVec2| i128::from(v.x.max(v.y));
+    let button = largest(button_a).max(largest(button_b));
+    let number = button.max(largest(prize));
+
+    prize.x.checked_add(button_b.x).is_some()
+        && prize.y.checked_add(button_b.y).is_some()
+        && number
+            .checked_mul(button)
+            .and_then(|v| v.checked_mul(button))
+            .and_then(|v| v.checked_mul(2))
+            .is_some()
+}
+
+/// The cheapest presses when the buttons move along the same line, or one of them doesn't move.
+///
+/// The prize has to be on that line, then it's a single equation `a * p + b * q == t` along one
+/// axis. Its whole solutions are spaced evenly and the cost changes linearly between them, so the
+/// cheapest is at one end of the range where both counts stay non-negative.
+pub fn solve_collinear(button_a: IVec2, button_b: IVec2, prize: IVec2) -> Option<(u64, u64)> {
+    let origin = IVec2::default();
+    if cross(origin, button_a, prize) != 0 || cross(origin, button_b, prize) != 0 {
+        return None;
+    }
+
+    // Project onto x unless the line is the y axis itself
+    let along_x = [button_a, button_b, prize].iter().any(|v| v.x != 0);
+    let axis = |v: IVec2| if along_x { v.x as i128 } else { v.y as i128 };
+    let (p, q, t) = (axis(button_a), axis(button_b), axis(prize));
+
+    let (a, b) = match (p, q) {
+        (0, 0) => (t == 0).then_some((0, 0))?,
+        (0, q) => (0, exact_div(t, q)?),
+        (p, 0) => (exact_div(t, p)?, 0),
+        (p, q) => {
+            let (g, x, y) = extended_gcd(p, q);
+            if t % g != 0 {
+                return None;
+            }
+            let scale = t / g;
+            // a = a0 + k * step_a, b = b0 - k * step_b for any whole k
+            let (a0, b0) = (x * scale, y * scale);
+            let (step_a, step_b) = (q / g, p / g);
+
+            let mut low = i128::MIN;
+            let mut high = i128::MAX;
+            for (start, step) in [(a0, step_a), (b0, -step_b)] {
+                // start + k * step >= 0
+                let bound = Rational::new(-start, step);
+                if step > 0 {
+                    low = low.max(bound.ceil());
+                } else {
+                    high = high.min(bound.floor());
+                }
+            }
+            if low > high {
+                return None;
+            }
+
+            [low, high]
+                .into_iter()
+                .filter(|k| *k != i128::MIN && *k != i128::MAX)
+                .map(|k| (a0 + k * step_a, b0 - k * step_b))
+                .min_by_key(|(a, b)| a * 3 + b)?
+        }
+    };
+    trace!("collinear presses: {a} {b}");
+
+    Some((a.try_into().ok()?, b.try_into().ok()?))
+}
+
+/// `n / d` if it divides evenly and comes out non-negative.
+fn exact_div(n: i128, d: i128) -> Option<i128> {
+    (n % d == 0 && n / d >= 0).then_some(n / d)
+}
+
+/// [`solve`] without a way to overflow, solving the two equations the presses have to meet with
+/// Cramer's rule.
+///
+/// Buttons moving the same way still go through [`solve_collinear`], so those are `Err` when the
+/// prize doesn't fit an `i64`.
+#[cfg(feature = "bigint")]
+pub fn solve_big(setup: &Setup, is_part_2: bool) -> Result<Option<(BigInt, BigInt)>, String> {
+    let [ax, ay, bx, by] = [
+        setup.button_a.x,
+        setup.button_a.y,
+        setup.button_b.x,
+        setup.button_b.y,
+    ]
+    .map(BigInt::from);
+    let mut px = BigInt::from(setup.prize.x);
+    let mut py = BigInt::from(setup.prize.y);
+    if is_part_2 {
+        px += BigInt::from(PART_2_OFFSET);
+        py += BigInt::from(PART_2_OFFSET);
+    }
+
+    let det = &ax * &by - &ay * &bx;
+    if det.is_zero() {
+        let prize = px
+            .to_i64()
+            .zip(py.to_i64())
+            .map(|(x, y)| IVec2 { x, y })
+            .ok_or_else(|| overflowed("the prize"))?;
+        let button_a = to_ivec2(setup.button_a, "button A")?;
+        let button_b = to_ivec2(setup.button_b, "button B")?;
+        let presses = solve_collinear(button_a, button_b, prize);
+        return Ok(presses.map(|(a, b)| (a.into(), b.into())));
+    }
+    let (a_presses, a_rest) = (&px * &by - &py * &bx).div_rem(&det);
+    let (b_presses, b_rest) = (&ax * &py - &ay * &px).div_rem(&det);
+    trace!("presses: {a_presses} {b_presses}");
+
+    let whole = a_rest.is_zero() && b_rest.is_zero();
+    Ok(
+        (whole && !a_presses.is_negative() && !b_presses.is_negative())
+            .then_some((a_presses, b_presses)),
+    )
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UVec2 {
+    pub x: u64,
+    pub y: u64,
+}
+
+impl TryFrom<UVec2> for IVec2 {
+    type Error = TryFromIntError;
+
+    fn try_from(value: UVec2) -> Result<Self, Self::Error> {
+        Ok(IVec2 {
+            x: value.x.try_into()?,
+            y: value.y.try_into()?,
+        })
+    }
+}
+
+/// A single claw machine.
+#[derive(Debug)]
+pub struct Setup {
+    pub button_a: UVec2,
+    pub button_b: UVec2,
+
+    pub prize: UVec2,
+}
+
+pub type ParsedInput = Vec<Setup>;
+
+pub fn parse_input(input: &str) -> ParsedInput {
+    let mut output = Vec::new();
+
+    let mut iter = input.trim().lines();
+
+    while let Some(button_a_str) = iter.next() {
+        let button_b_str = iter.next().unwrap();
+        let prize_str = iter.next().unwrap();
+
+        iter.next();
+
+        output.push(Setup {
+            button_a: parse_coords(button_a_str),
+            button_b: parse_coords(button_b_str),
+            prize: parse_coords(prize_str),
+        });
+    }
+
+    output
+}
+
+/// Parses the `X+1, Y+2` or `X=1, Y=2` part of a line.
+pub fn parse_coords(input: &str) -> UVec2 {
+    let mut split = input.split(":");
+    split.next();
+    trace!("coords: {input}");
+    let components = split.next().unwrap();
+
+    let mut x = 0u64;
+    let mut y = 0u64;
+
+    for component_str in components.trim().split(',') {
+        let trimmed = component_str.trim();
+        if let Some(x_str) = trimmed.strip_prefix("X=") {
+            x = x_str.parse().unwrap();
+        }
+        if let Some(x_str) = trimmed.strip_prefix("X+") {
+            x = x_str.parse().unwrap();
+        }
+
+        if let Some(y_str) = trimmed.strip_prefix("Y=") {
+            y = y_str.parse().unwrap();
+        }
+        if let Some(y_str) = trimmed.strip_prefix("Y+") {
+            y = y_str.parse().unwrap();
+        }
+    }
+
+    UVec2 { x, y }
+}
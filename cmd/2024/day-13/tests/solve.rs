@@ -0,0 +1,121 @@
+use aoc_2024_day_13::{parse_input, part_1, part_2, solve, Setup, UVec2};
+
+fn setup(a: (u64, u64), b: (u64, u64), prize: (u64, u64)) -> Setup {
+    let vec = |(x, y)| UVec2 { x, y };
+    Setup {
+        button_a: vec(a),
+        button_b: vec(b),
+        prize: vec(prize),
+    }
+}
+
+#[test]
+fn button_without_x_movement() {
+    // 3 presses of A for y, then 4 of B covers x and the rest of y
+    assert_eq!(
+        solve(&setup((0, 5), (2, 1), (8, 19)), false),
+        Ok(Some((3, 4)))
+    );
+    assert_eq!(
+        solve(&setup((2, 1), (0, 5), (8, 19)), false),
+        Ok(Some((4, 3)))
+    );
+}
+
+#[test]
+fn collinear_buttons_pick_the_cheapest_presses() {
+    // A moves 2 for 3 tokens and B 1 for 1, so only B
+    assert_eq!(
+        solve(&setup((2, 2), (1, 1), (7, 7)), false),
+        Ok(Some((0, 7)))
+    );
+    // A moves 4 for 3 tokens, so as many A presses as still leave a whole number of B presses
+    assert_eq!(
+        solve(&setup((4, 8), (1, 2), (10, 20)), false),
+        Ok(Some((2, 2)))
+    );
+    // 10 only splits into one 4 and one 6
+    assert_eq!(
+        solve(&setup((4, 0), (6, 0), (10, 0)), false),
+        Ok(Some((1, 1)))
+    );
+}
+
+#[test]
+fn collinear_buttons_off_the_prize_line() {
+    assert_eq!(solve(&setup((1, 1), (2, 2), (3, 4)), false), Ok(None));
+    assert_eq!(solve(&setup((2, 2), (4, 4), (3, 3)), false), Ok(None));
+}
+
+#[test]
+fn example_token_counts() {
+    let parsed = parse_input(aoc_2024_day_13::EXAMPLE_INPUT);
+    assert_eq!(
+        part_1(&parsed).map(|v| v.to_string()),
+        Ok("480".to_string())
+    );
+    assert_eq!(
+        part_2(&parsed).map(|v| v.to_string()),
+        Ok("875318608908".to_string())
+    );
+}
+
+#[cfg(not(feature = "bigint"))]
+#[test]
+fn numbers_past_i64_need_the_feature() {
+    let expected =
+        Err("the prize overflows, build with the bigint feature to solve it".to_string());
+    assert_eq!(
+        solve(&setup((1, 0), (0, 1), (u64::MAX, 1)), false),
+        expected
+    );
+    assert_eq!(
+        solve(&setup((1, 0), (0, 1), (u64::MAX - 1, 1)), true),
+        expected
+    );
+
+    let expected =
+        Err("the line crossing overflows, build with the bigint feature to solve it".to_string());
+    let big = 1 << 40;
+    assert_eq!(
+        solve(&setup((big, 1), (1, big), (1 << 60, 1 << 60)), false),
+        expected
+    );
+    assert_eq!(
+        solve(&setup((1, 0), (1, 1), (i64::MAX as u64, 1)), false),
+        expected
+    );
+
+    let expected = Err("button A overflows, build with the bigint feature to solve it".to_string());
+    assert_eq!(
+        solve(&setup((u64::MAX, 0), (0, 1), (1, 1)), false),
+        expected
+    );
+}
+
+#[cfg(not(feature = "bigint"))]
+#[test]
+fn token_counts_past_u64_need_the_feature() {
+    // Each prize takes i64::MAX - 1 presses of B, three of them add up past a u64
+    let machine = format!(
+        "Button A: X+2, Y+0\nButton B: X+1, Y+1\nPrize: X={0}, Y={0}\n",
+        i64::MAX - 1
+    );
+    let parsed = parse_input(&[machine.as_str(), machine.as_str(), machine.as_str()].join("\n"));
+    assert_eq!(
+        part_1(&parsed),
+        Err("the token count overflows, build with the bigint feature to solve it".to_string())
+    );
+}
+
+#[cfg(feature = "bigint")]
+#[test]
+fn token_counts_past_u64_with_big_integers() {
+    let machine = format!(
+        "Button A: X+2, Y+0\nButton B: X+1, Y+1\nPrize: X={0}, Y={0}\n",
+        i64::MAX - 1
+    );
+    let parsed = parse_input(&[machine.as_str(), machine.as_str(), machine.as_str()].join("\n"));
+    let expected = ((i64::MAX - 1) as u128 * 3).to_string();
+    assert_eq!(part_1(&parsed).map(|v| v.to_string()), Ok(expected));
+}
@@ -0,0 +1,293 @@
+use std::{convert::Infallible, str::FromStr};
+
+use common::{
+    debug,
+    math::crt,
+    occupancy::OccupancyGrid,
+    progress::Progress,
+    render::{Cell, Color, Renderer, Style},
+    simulation::Simulation,
+    trace, IVec2,
+};
+
+pub const INPUT: &str = include_str!("input.txt");
+pub const EXAMPLE_INPUT: &str = r#"
+p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3
+"#;
+
+/// Safety factor, the product of the robot counts per quadrant after 100 seconds.
+pub fn part_1(parsed_input: &ParsedInput) -> u32 {
+    let mut cloned = parsed_input.clone();
+
+    step_all_n(&mut cloned, 100);
+
+    let quadrants = cloned.occupancy().quadrant_counts();
+
+    debug!("quadrants: {quadrants:?}");
+    quadrants.iter().product()
+}
+
+/// Number of seconds until the robots first arrange into a christmas tree.
+///
+/// The x positions repeat every `size.x` seconds and the y positions every `size.y`. In the tree
+/// frame the robots bunch up on both axes, so the second with the least spread on each axis
+/// gives the tree's second modulo that axis' period, and the CRT combines the two.
+///
+/// If that frame isn't a tree every frame of the cycle gets checked, failing if none is.
+pub fn part_2(parsed_input: &ParsedInput) -> Result<usize, String> {
+    let size = parsed_input.size;
+    let least_spread = |axis: fn(IVec2) -> i64, period: i64| {
+        (0..period)
+            .min_by_key(|&seconds| {
+                spread(
+                    parsed_input
+                        .robots
+                        .iter()
+                        .map(|v| axis(v.position_after(seconds, size))),
+                )
+            })
+            .unwrap()
+    };
+
+    let x = least_spread(|v| v.x, size.x);
+    let y = least_spread(|v| v.y, size.y);
+    let (seconds, cycle) = crt(&[(x, size.x), (y, size.y)]).expect("board sizes are coprime");
+    if is_christmas(&parsed_input.after(seconds)) {
+        return Ok(seconds as usize);
+    }
+
+    debug!("no tree after {seconds}s, checking every frame");
+    (0..cycle)
+        .find(|&seconds| {
+            common::cancel::checkpoint();
+            is_christmas(&parsed_input.after(seconds))
+        })
+        .map(|seconds| seconds as usize)
+        .ok_or_else(|| format!("no christmas tree within {cycle} steps"))
+}
+
+/// Sum of squared distances to the mean, times the number of values to stay in integers.
+fn spread(values: impl Iterator<Item = i64> + Clone) -> i64 {
+    let count = values.clone().count() as i64;
+    let sum: i64 = values.clone().sum();
+    let sum_of_squares: i64 = values.map(|v| v * v).sum();
+    count * sum_of_squares - sum * sum
+}
+
+/// Moves every robot by its velocity once.
+pub fn step_all(parsed_input: &mut ParsedInput) {
+    let size = parsed_input.size;
+    for robot in parsed_input.robots.iter_mut() {
+        let from = robot.pos;
+        robot.step(size);
+        parsed_input.occupancy.move_entity(from, robot.pos);
+    }
+}
+
+/// The robots moving around the board, every robot is back where it started after
+/// `size.x * size.y` steps at the latest.
+pub struct Robots;
+
+impl Simulation for Robots {
+    type State = ParsedInput;
+    type Key = Vec<IVec2>;
+
+    fn step(&self, state: &mut ParsedInput) {
+        step_all(state);
+    }
+
+    fn key(&self, state: &ParsedInput) -> Vec<IVec2> {
+        state.robots.iter().map(|v| v.pos).collect()
+    }
+}
+
+/// The board after `steps` seconds, jumping ahead with the cycle the robots move in.
+pub fn simulate(input: &str, steps: u64) -> String {
+    render_board(&Robots.state_at(&parse_input(input), steps))
+}
+
+/// Steps all robots `n` times.
+pub fn step_all_n(parsed_input: &mut ParsedInput, n: usize) {
+    let mut progress = Progress::new("steps", n as u64);
+    for _ in 0..n {
+        common::cancel::checkpoint();
+        step_all(parsed_input);
+        progress.inc();
+    }
+}
+
+/// Whether there's a filled 5x5 block of robots somewhere, which only happens in the tree frame.
+///
+/// A 3x3 block also shows up by chance in some frames before the tree.
+pub fn is_christmas(input: &ParsedInput) -> bool {
+    let grid = input.occupancy();
+    grid.occupied()
+        .any(|(pos, _)| grid.occupied_around(pos, 2) == 25)
+}
+
+/// Prints the number of robots on every tile, leaving empty tiles blank, coloured on a terminal.
+pub fn print_board(input: &ParsedInput) {
+    let renderer = Renderer::new()
+        .legend(
+            Cell::styled("1", Style::default().fg(Color::Green)),
+            "one robot",
+        )
+        .legend(
+            Cell::styled("2", Style::default().fg(Color::BrightRed).bold()),
+            "more robots",
+        );
+    print!("{}", draw_board(input, &renderer));
+}
+
+/// The board as printed by [`print_board`], without colour or legend.
+pub fn render_board(input: &ParsedInput) -> String {
+    draw_board(input, &Renderer::new().color(false))
+}
+
+fn draw_board(input: &ParsedInput, renderer: &Renderer) -> String {
+    let grid = input.occupancy();
+    renderer.render(
+        input.size.x as usize,
+        input.size.y as usize,
+        |x, y| match grid.count_at(IVec2 {
+            x: x as i64,
+            y: y as i64,
+        }) {
+            0 => Cell::new(" "),
+            1 => Cell::styled("1", Style::default().fg(Color::Green)),
+            n => Cell::styled(n.to_string(), Style::default().fg(Color::BrightRed).bold()),
+        },
+    )
+}
+
+/// Parses `x,y`.
+fn parse_vec(s: &str) -> IVec2 {
+    trace!("vector: {s}");
+    let mut split = s.split(',');
+    let x = split.next().unwrap().parse().unwrap();
+    let y = split.next().unwrap().parse().unwrap();
+
+    IVec2 { x, y }
+}
+
+/// Sizes of the example's board and the real one, smallest first.
+pub const BOARD_SIZES: [IVec2; 2] = [IVec2 { x: 11, y: 7 }, IVec2 { x: 101, y: 103 }];
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Robot {
+    pub pos: IVec2,
+    pub vel: IVec2,
+}
+
+impl Robot {
+    /// Where the robot is after the given number of seconds on a board of `size`.
+    pub fn position_after(&self, seconds: i64, size: IVec2) -> IVec2 {
+        IVec2 {
+            x: (self.pos.x + self.vel.x * seconds).rem_euclid(size.x),
+            y: (self.pos.y + self.vel.y * seconds).rem_euclid(size.y),
+        }
+    }
+
+    /// Moves by the velocity once, wrapping around the edges of a board of `size`.
+    pub fn step(&mut self, size: IVec2) {
+        self.pos.x += self.vel.x;
+        self.pos.y += self.vel.y;
+
+        self.pos.x %= size.x;
+        self.pos.y %= size.y;
+
+        if self.pos.x < 0 {
+            self.pos.x += size.x;
+        }
+        if self.pos.y < 0 {
+            self.pos.y += size.y;
+        }
+    }
+}
+
+impl FromStr for Robot {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // p=0,4 v=3,-3
+        let mut split = s.split(' ');
+        let p_part = parse_vec(split.next().unwrap().trim().trim_start_matches("p="));
+        let v_part = parse_vec(split.next().unwrap().trim().trim_start_matches("v="));
+
+        Ok(Self {
+            pos: p_part,
+            vel: v_part,
+        })
+    }
+}
+
+/// The robots on their board, with the robot counts per tile kept up to date as they step.
+#[derive(Debug, Clone)]
+pub struct ParsedInput {
+    robots: Vec<Robot>,
+    size: IVec2,
+    occupancy: OccupancyGrid,
+}
+
+impl ParsedInput {
+    pub fn new(robots: Vec<Robot>, size: IVec2) -> Self {
+        let occupancy = OccupancyGrid::from_positions(size.x, size.y, robots.iter().map(|v| v.pos));
+        Self {
+            robots,
+            size,
+            occupancy,
+        }
+    }
+
+    pub fn robots(&self) -> &[Robot] {
+        &self.robots
+    }
+
+    /// Width and height of the board, the smallest of [`BOARD_SIZES`] every robot starts on.
+    pub fn size(&self) -> IVec2 {
+        self.size
+    }
+
+    /// Robot counts per tile of the board.
+    pub fn occupancy(&self) -> &OccupancyGrid {
+        &self.occupancy
+    }
+
+    /// The robots after the given number of seconds.
+    pub fn after(&self, seconds: i64) -> ParsedInput {
+        let robots = self
+            .robots
+            .iter()
+            .map(|v| Robot {
+                pos: v.position_after(seconds, self.size),
+                vel: v.vel,
+            })
+            .collect();
+        ParsedInput::new(robots, self.size)
+    }
+}
+
+pub fn parse_input(input: &str) -> ParsedInput {
+    let robots: Vec<Robot> = input.trim().lines().map(|v| v.parse().unwrap()).collect();
+    let size = BOARD_SIZES
+        .into_iter()
+        .find(|size| {
+            robots
+                .iter()
+                .all(|v| (0..size.x).contains(&v.pos.x) && (0..size.y).contains(&v.pos.y))
+        })
+        .expect("robots start off every board");
+
+    ParsedInput::new(robots, size)
+}
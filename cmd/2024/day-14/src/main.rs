@@ -0,0 +1,29 @@
+use aoc_2024_day_14::{parse_input, part_1, part_2, print_board, step_all_n, EXAMPLE_INPUT, INPUT};
+
+fn main() {
+    common::log::init_from_args();
+
+    let use_example = std::env::args().any(|v| v == "--example");
+
+    println!("Launching, using example. {use_example}");
+
+    let input = common::input::load(if use_example { EXAMPLE_INPUT } else { INPUT });
+    let parsed = parse_input(&input);
+
+    println!("part 1");
+    print_board(&parsed);
+    println!();
+    println!("Result: {}", part_1(&parsed));
+
+    println!("=======");
+    println!("part 2");
+    match part_2(&parsed) {
+        Ok(steps) => {
+            let mut tree = parsed.clone();
+            step_all_n(&mut tree, steps);
+            print_board(&tree);
+            println!("Result: {steps}");
+        }
+        Err(err) => println!("Failed: {err}"),
+    }
+}
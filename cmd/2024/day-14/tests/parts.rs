@@ -0,0 +1,40 @@
+use aoc_2024_day_14::{
+    parse_input, part_1, part_2, step_all_n, ParsedInput, BOARD_SIZES, EXAMPLE_INPUT,
+};
+use common::IVec2;
+
+#[test]
+fn example_uses_the_small_board() {
+    let parsed = parse_input(EXAMPLE_INPUT);
+
+    assert_eq!(parsed.size(), BOARD_SIZES[0]);
+    assert_eq!(part_1(&parsed), 12);
+}
+
+#[test]
+fn robots_past_the_example_board_use_the_real_one() {
+    let parsed = parse_input("p=0,4 v=3,-3\np=11,0 v=1,1");
+    assert_eq!(parsed.size(), IVec2 { x: 101, y: 103 });
+}
+
+#[test]
+fn fails_without_a_tree_in_the_whole_cycle() {
+    assert_eq!(
+        part_2(&parse_input(EXAMPLE_INPUT)),
+        Err("no christmas tree within 77 steps".to_string())
+    );
+}
+
+#[test]
+fn occupancy_follows_the_robots() {
+    let mut robots = parse_input(EXAMPLE_INPUT);
+    step_all_n(&mut robots, 37);
+
+    let rebuilt = ParsedInput::new(robots.robots().to_vec(), robots.size());
+    assert_eq!(robots.occupancy(), rebuilt.occupancy());
+    assert_eq!(
+        robots.occupancy(),
+        parse_input(EXAMPLE_INPUT).after(37).occupancy()
+    );
+    assert_eq!(robots.occupancy().total(), 12);
+}
@@ -0,0 +1,14 @@
+use aoc_2024_day_14::{parse_input, render_board, step_all_n, EXAMPLE_INPUT};
+use common::assert_snapshot;
+
+#[test]
+fn example_board() {
+    assert_snapshot!("example_board", render_board(&parse_input(EXAMPLE_INPUT)));
+}
+
+#[test]
+fn example_board_after_100_steps() {
+    let mut robots = parse_input(EXAMPLE_INPUT);
+    step_all_n(&mut robots, 100);
+    assert_snapshot!("example_board_after_100_steps", render_board(&robots));
+}
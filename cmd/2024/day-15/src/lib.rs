@@ -1,7 +1,13 @@
-use common::IVec2;
-
-const INPUT: &str = include_str!("input.txt");
-const EXAMPLE_INPUT: &str = r#"
+use common::{
+    debug,
+    grid_parser::{Count, GridParser},
+    progress::Progress,
+    render::{Cell, Color, Renderer, Style},
+    IVec2,
+};
+
+pub const INPUT: &str = include_str!("input.txt");
+pub const EXAMPLE_INPUT: &str = r#"
 ##########
 #..O..O.O#
 #......O.#
@@ -26,50 +32,54 @@ v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
 "#;
 // <vv<<^^<<^^
 
-fn main() {
-    let use_example = std::env::args().any(|v| v == "--example");
-
-    println!("Launching, using example. {use_example}");
-
-    let parsed_p1 = parse_input(use_example.then_some(EXAMPLE_INPUT).unwrap_or(INPUT), false);
-
-    println!("part 1");
-    part_1(parsed_p1);
-
-    let parsed_p2 = parse_input(use_example.then_some(EXAMPLE_INPUT).unwrap_or(INPUT), true);
-    println!("=======");
-    println!("part 2");
-    part_2(parsed_p2);
+/// Sum of the GPS coordinates of all boxes after the robot is done moving.
+pub fn part_1(parsed_input: &ParsedInput) -> i64 {
+    let mut state = parsed_input.state.clone();
+    state.step_all(&parsed_input.moves);
+    let coords = state.all_box_coords();
+    coords.iter().map(|v| (v.y * 100) + v.x).sum()
 }
 
-fn part_1(mut parsed_input: ParsedInput) {
-    parsed_input.state.step_all(&parsed_input.moves);
-    let coords = parsed_input.state.all_box_coords();
-    let sum: i64 = coords.iter().map(|v| (v.y * 100) + v.x).sum();
-    println!("Sum: {sum}");
-}
-
-fn part_2(mut parsed_input: ParsedInput) {
-    parsed_input.state.print();
+/// Like [`part_1`], but in the twice as wide warehouse.
+pub fn part_2(parsed_input: &ParsedInput) -> i64 {
+    let mut state = parsed_input.state.widen();
+    debug!("wide warehouse:\n{}", state.render());
 
-    parsed_input.state.step_all(&parsed_input.moves);
-    let coords = parsed_input.state.all_box_coords();
-    let sum: i64 = coords.iter().map(|v| (v.y * 100) + v.x).sum();
-    println!("Sum: {sum}");
+    state.step_all(&parsed_input.moves);
+    let coords = state.all_box_coords();
+    coords.iter().map(|v| (v.y * 100) + v.x).sum()
 }
 
+const ROBOT_STYLE: Style = Style {
+    fg: Some(Color::BrightYellow),
+    bg: None,
+    bold: true,
+};
+const WALL_STYLE: Style = Style {
+    fg: Some(Color::Blue),
+    bg: None,
+    bold: false,
+};
+const BOX_STYLE: Style = Style {
+    fg: Some(Color::BrightGreen),
+    bg: None,
+    bold: false,
+};
+
 #[derive(Debug, Clone, Copy)]
-enum Spot {
+pub enum Spot {
     Free,
     Wall,
     // Robot,
     Box,
+    /// Left half of a wide box
     BoxL,
+    /// Right half of a wide box
     BoxR,
 }
 
 #[derive(Debug, Clone, Copy)]
-enum Move {
+pub enum Move {
     Up,
     Left,
     Down,
@@ -77,7 +87,7 @@ enum Move {
 }
 
 impl Move {
-    fn ivec_dir(&self) -> IVec2 {
+    pub fn ivec_dir(&self) -> IVec2 {
         match self {
             Move::Up => IVec2 { x: 0, y: -1 },
             Move::Left => IVec2 { x: -1, y: 0 },
@@ -87,23 +97,25 @@ impl Move {
     }
 }
 
+/// The warehouse and the robot moving around in it.
 #[derive(Debug, Clone)]
-struct State {
-    board: Board,
-    robot_pos: IVec2,
+pub struct State {
+    pub board: Board,
+    pub robot_pos: IVec2,
 }
 
-type Board = Vec<Vec<Spot>>;
+pub type Board = Vec<Vec<Spot>>;
 
 impl State {
-    fn dims(&self) -> IVec2 {
+    pub fn dims(&self) -> IVec2 {
         IVec2 {
             x: self.board[0].len() as i64,
             y: self.board.len() as i64,
         }
     }
 
-    fn try_push_box(&mut self, pos: IVec2, dir: IVec2) -> bool {
+    /// Pushes the box at `pos` and everything stacked behind it, returns false if a wall is in the way.
+    pub fn try_push_box(&mut self, pos: IVec2, dir: IVec2) -> bool {
         let mut needs_to_follow = self.get_push_follow(pos).unwrap();
         let mut leaf = needs_to_follow.clone();
 
@@ -119,19 +131,11 @@ impl State {
                     Spot::Box => true,
                     Spot::BoxL => {
                         // check only if going left or up/down
-                        if dir.y != 0 || dir.x < 0 {
-                            true
-                        } else {
-                            false
-                        }
+                        dir.y != 0 || dir.x < 0
                     }
                     Spot::BoxR => {
                         // check only if going right or up/down
-                        if dir.y != 0 || dir.x > 0 {
-                            true
-                        } else {
-                            false
-                        }
+                        dir.y != 0 || dir.x > 0
                     }
                 };
                 // dbg!(should_check, spot, dir,);
@@ -152,7 +156,7 @@ impl State {
 
             leaf = new_leaf;
 
-            if leaf.len() < 1 {
+            if leaf.is_empty() {
                 // done
                 break;
             }
@@ -202,7 +206,7 @@ impl State {
         }
     }
 
-    fn is_in_bounds(&self, pos: IVec2) -> bool {
+    pub fn is_in_bounds(&self, pos: IVec2) -> bool {
         let dims = self.dims();
 
         if pos.x < 0 || pos.y < 0 || pos.x >= dims.x || pos.y >= dims.y {
@@ -212,7 +216,8 @@ impl State {
         true
     }
 
-    fn move_robot(&mut self, movement: Move) -> bool {
+    /// Moves the robot once, pushing boxes out of the way, returns whether it moved.
+    pub fn move_robot(&mut self, movement: Move) -> bool {
         let new_pos = self.robot_pos + movement.ivec_dir();
         if !self.is_in_bounds(new_pos) {
             return false;
@@ -233,15 +238,18 @@ impl State {
         true
     }
 
-    fn step_all(&mut self, movement: &Vec<Move>) {
-        for (i, movement) in movement.iter().enumerate() {
+    /// Applies every move in order.
+    pub fn step_all(&mut self, movement: &[Move]) {
+        let mut progress = Progress::new("moves", movement.len() as u64);
+        for movement in movement {
             self.move_robot(*movement);
-            println!("\n{i}: {movement:?}");
+            progress.inc();
             // self.print();
         }
     }
 
-    fn all_box_coords(&self) -> Vec<IVec2> {
+    /// Positions of all boxes, using the left half for wide boxes.
+    pub fn all_box_coords(&self) -> Vec<IVec2> {
         let mut coords = Vec::new();
         for (y, row) in self.board.iter().enumerate() {
             for (x, cell) in row.iter().enumerate() {
@@ -266,79 +274,80 @@ impl State {
         coords
     }
 
-    fn print(&self) {
-        for (y, row) in self.board.iter().enumerate() {
-            for (x, cell) in row.iter().enumerate() {
-                if y as i64 == self.robot_pos.y && x as i64 == self.robot_pos.x {
-                    print!("@");
-                    continue;
-                }
-                match cell {
-                    Spot::Free => print!("."),
-                    Spot::Wall => print!("#"),
-                    Spot::Box => print!("O"),
-                    Spot::BoxL => print!("["),
-                    Spot::BoxR => print!("]"),
-                }
-            }
-            println!()
+    /// Returns the warehouse with everything except the robot twice as wide.
+    pub fn widen(&self) -> State {
+        let board = self
+            .board
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .flat_map(|spot| match spot {
+                        Spot::Box => [Spot::BoxL, Spot::BoxR],
+                        _ => [*spot, *spot],
+                    })
+                    .collect()
+            })
+            .collect();
+
+        State {
+            board,
+            robot_pos: IVec2 {
+                x: self.robot_pos.x * 2,
+                y: self.robot_pos.y,
+            },
         }
     }
+
+    /// Prints the warehouse in the puzzle notation, coloured on a terminal.
+    pub fn print(&self) {
+        let renderer = Renderer::new()
+            .legend(Cell::styled("@", ROBOT_STYLE), "robot")
+            .legend(Cell::styled("#", WALL_STYLE), "wall")
+            .legend(Cell::styled("O", BOX_STYLE), "box");
+        print!("{}", self.draw(&renderer));
+    }
+
+    /// The warehouse as printed by [`State::print`], without colour or legend.
+    pub fn render(&self) -> String {
+        self.draw(&Renderer::new().color(false))
+    }
+
+    fn draw(&self, renderer: &Renderer) -> String {
+        let width = self.board.first().map_or(0, |row| row.len());
+        renderer.render(width, self.board.len(), |x, y| {
+            if y as i64 == self.robot_pos.y && x as i64 == self.robot_pos.x {
+                return Cell::styled("@", ROBOT_STYLE);
+            }
+            match self.board[y][x] {
+                Spot::Free => Cell::styled(".", Style::default().fg(Color::Gray)),
+                Spot::Wall => Cell::styled("#", WALL_STYLE),
+                Spot::Box => Cell::styled("O", BOX_STYLE),
+                Spot::BoxL => Cell::styled("[", BOX_STYLE),
+                Spot::BoxR => Cell::styled("]", BOX_STYLE),
+            }
+        })
+    }
 }
 
 #[derive(Debug, Clone)]
-struct ParsedInput {
-    state: State,
-    moves: Vec<Move>,
+pub struct ParsedInput {
+    pub state: State,
+    pub moves: Vec<Move>,
 }
 
-fn parse_input(input: &str, is_part_2: bool) -> ParsedInput {
+pub fn parse_input(input: &str) -> ParsedInput {
     let (board_str, moves_str) = input.trim().split_once("\n\n").unwrap();
 
-    let mut board = Vec::new();
-    let mut robot_pos = None;
-    for (y, line) in board_str.lines().enumerate() {
-        let mut row = Vec::new();
-
-        for (x, c) in line.chars().enumerate() {
-            match c {
-                '.' => {
-                    row.push(Spot::Free);
-                    if is_part_2 {
-                        row.push(Spot::Free);
-                    }
-                }
-                '#' => {
-                    row.push(Spot::Wall);
-                    if is_part_2 {
-                        row.push(Spot::Wall);
-                    }
-                }
-                '@' => {
-                    robot_pos = Some(IVec2 {
-                        x: if is_part_2 { x as i64 * 2 } else { x as i64 },
-                        y: y as i64,
-                    });
-
-                    row.push(Spot::Free);
-                    if is_part_2 {
-                        row.push(Spot::Free);
-                    }
-                }
-                'O' => {
-                    if is_part_2 {
-                        row.push(Spot::BoxL);
-                        row.push(Spot::BoxR);
-                    } else {
-                        row.push(Spot::Box);
-                    }
-                }
-                _ => panic!("unknown dings {c}"),
-            }
-        }
-
-        board.push(row);
-    }
+    let parsed = GridParser::new(|c| match c {
+        '.' | '@' => Some(Spot::Free),
+        '#' => Some(Spot::Wall),
+        'O' => Some(Spot::Box),
+        _ => None,
+    })
+    .marker('@', Count::ExactlyOne)
+    .parse(board_str)
+    .unwrap_or_else(|e| panic!("bad warehouse: {e}"));
+    let board = parsed.grid.rows().map(<[_]>::to_vec).collect();
 
     let mut moves = Vec::new();
     for char in moves_str.chars() {
@@ -360,7 +369,7 @@ fn parse_input(input: &str, is_part_2: bool) -> ParsedInput {
     ParsedInput {
         state: State {
             board,
-            robot_pos: robot_pos.unwrap(),
+            robot_pos: parsed.one('@'),
         },
         moves,
     }
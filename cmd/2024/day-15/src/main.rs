@@ -0,0 +1,19 @@
+use aoc_2024_day_15::{parse_input, part_1, part_2, EXAMPLE_INPUT, INPUT};
+
+fn main() {
+    common::log::init_from_args();
+
+    let use_example = std::env::args().any(|v| v == "--example");
+
+    println!("Launching, using example. {use_example}");
+
+    let input = common::input::load(if use_example { EXAMPLE_INPUT } else { INPUT });
+    let parsed = parse_input(&input);
+
+    println!("part 1");
+    println!("Sum: {}", part_1(&parsed));
+
+    println!("=======");
+    println!("part 2");
+    println!("Sum: {}", part_2(&parsed));
+}
@@ -0,0 +1,30 @@
+use aoc_2024_day_15::{parse_input, EXAMPLE_INPUT};
+use common::assert_snapshot;
+
+#[test]
+fn example_warehouse() {
+    let parsed = parse_input(EXAMPLE_INPUT);
+    assert_snapshot!("example_warehouse", parsed.state.render());
+}
+
+#[test]
+fn example_warehouse_after_moves() {
+    let parsed = parse_input(EXAMPLE_INPUT);
+    let mut state = parsed.state.clone();
+    state.step_all(&parsed.moves);
+    assert_snapshot!("example_warehouse_after_moves", state.render());
+}
+
+#[test]
+fn example_wide_warehouse() {
+    let parsed = parse_input(EXAMPLE_INPUT);
+    assert_snapshot!("example_wide_warehouse", parsed.state.widen().render());
+}
+
+#[test]
+fn example_wide_warehouse_after_moves() {
+    let parsed = parse_input(EXAMPLE_INPUT);
+    let mut state = parsed.state.widen();
+    state.step_all(&parsed.moves);
+    assert_snapshot!("example_wide_warehouse_after_moves", state.render());
+}
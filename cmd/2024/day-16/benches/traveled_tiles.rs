@@ -0,0 +1,17 @@
+//! Solving the maze with the cheapest cost per tile hashed by SipHash and by Fx.
+
+use std::hash::RandomState;
+
+use aoc_2024_day_16::{parse_input, INPUT};
+use common::{bench::bench, hash::FxBuildHasher};
+
+fn main() {
+    let parsed = parse_input(INPUT);
+
+    bench("HashMap (SipHash)", 5, || {
+        parsed.solve_maze_with_hasher::<RandomState>().paths.len()
+    });
+    bench("FastMap (Fx)", 5, || {
+        parsed.solve_maze_with_hasher::<FxBuildHasher>().paths.len()
+    });
+}
@@ -0,0 +1,287 @@
+use std::{collections::HashMap, hash::BuildHasher};
+
+use common::{
+    grid_parser::{Count, GridParser},
+    hash::{FastSet, FxBuildHasher},
+    path_arena::{PathArena, PathId},
+    progress::Progress,
+    render::{Cell, Color, Renderer, Style},
+    IVec2,
+};
+
+pub const INPUT: &str = include_str!("input.txt");
+pub const EXAMPLE_INPUT: &str = r#"
+#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################
+"#;
+
+/// Lowest score a reindeer can get walking from the start to the end.
+pub fn part_1(parsed_input: &ParsedInput) -> u64 {
+    let Paths { paths, .. } = parsed_input.solve_maze();
+    let lowest_score_path = paths
+        .iter()
+        .filter(|v| v.complete)
+        .min_by_key(|v| v.cost)
+        .unwrap();
+    lowest_score_path.cost
+}
+
+/// Number of tiles that are part of at least one of the lowest score paths.
+pub fn part_2(parsed_input: &ParsedInput) -> usize {
+    best_tiles(parsed_input).len()
+}
+
+/// Every tile on at least one of the lowest scoring paths.
+pub fn best_tiles(parsed_input: &ParsedInput) -> FastSet<IVec2> {
+    let Paths { tiles, paths } = parsed_input.solve_maze();
+    let lowest_score_path = paths
+        .iter()
+        .filter(|v| v.complete)
+        .min_by_key(|v| v.cost)
+        .unwrap();
+
+    paths
+        .iter()
+        .filter(|v| v.cost == lowest_score_path.cost)
+        .flat_map(|path| tiles.iter(path.path).copied())
+        .collect()
+}
+
+/// Prints the maze with the given tiles highlighted, coloured on a terminal.
+pub fn print_maze(parsed_input: &ParsedInput, highlighted: &FastSet<IVec2>) {
+    let to_cell = |pos: &IVec2| (pos.x as usize, pos.y as usize);
+    let renderer = Renderer::new()
+        .legend(Cell::styled("#", WALL_STYLE), "wall")
+        .mark(
+            "on a best path",
+            highlighted.iter().map(to_cell),
+            "O",
+            Style::default().fg(Color::BrightGreen).bg(Color::Green),
+        )
+        .mark(
+            "start and end",
+            [to_cell(&parsed_input.start), to_cell(&parsed_input.end)],
+            "*",
+            Style::default().fg(Color::BrightYellow).bold(),
+        );
+
+    let maze = &parsed_input.maze;
+    let width = maze.first().map_or(0, |row| row.len());
+    let out = renderer.render(width, maze.len(), |x, y| {
+        if maze[y][x] {
+            Cell::styled("#", WALL_STYLE)
+        } else {
+            Cell::new(".")
+        }
+    });
+    print!("{out}");
+}
+
+const DIRECTIONS: [IVec2; 4] = [
+    IVec2 { x: 1, y: 0 },
+    IVec2 { x: 0, y: 1 },
+    IVec2 { x: -1, y: 0 },
+    IVec2 { x: 0, y: -1 },
+];
+
+const WALL_STYLE: Style = Style {
+    fg: Some(Color::Gray),
+    bg: None,
+    bold: false,
+};
+
+/// The maze, with `true` for walls.
+#[derive(Debug, Clone)]
+pub struct ParsedInput {
+    pub start: IVec2,
+    pub end: IVec2,
+    pub maze: Vec<Vec<bool>>,
+}
+
+impl ParsedInput {
+    /// Grows paths from the start in every direction until none of them can get any cheaper,
+    /// returns all of them, complete or not.
+    pub fn solve_maze(&self) -> Paths {
+        self.solve_maze_with_hasher::<FxBuildHasher>()
+    }
+
+    /// [`ParsedInput::solve_maze`] with the cheapest cost per tile kept in a map hashed by `S`,
+    /// for benchmarking hashers against each other.
+    pub fn solve_maze_with_hasher<S: BuildHasher + Default>(&self) -> Paths {
+        let mut traveled_tiles = HashMap::<IVec2, (usize, u64), S>::default();
+        let mut tiles = PathArena::new();
+        let mut active_paths = vec![Path {
+            path: tiles.start(self.start),
+            cost: 0,
+            direction: 0,
+            complete: false,
+        }];
+        traveled_tiles.insert(self.start, (0, 0));
+
+        let mut new_paths = Vec::new();
+        let mut progress = Progress::unbounded("growing paths");
+        loop {
+            common::cancel::checkpoint();
+            let mut did_grow = false;
+            for (path_i, active_path) in active_paths.iter_mut().enumerate() {
+                // step 1 grow in all possible directions, creating new paths
+                let mut grow_ourselves = None;
+
+                let mut pot_dir_clockwise = active_path.direction + 1;
+                if pot_dir_clockwise >= DIRECTIONS.len() {
+                    pot_dir_clockwise -= DIRECTIONS.len()
+                }
+
+                let pot_dir_counter_clockwise = if active_path.direction == 0 {
+                    DIRECTIONS.len() - 1
+                } else {
+                    active_path.direction - 1
+                };
+
+                let tail = *tiles.last(active_path.path);
+                if tail == self.end {
+                    active_path.complete = true;
+                    continue;
+                }
+
+                'OUTER: for grow_dir_index in [
+                    active_path.direction,
+                    pot_dir_clockwise,
+                    pot_dir_counter_clockwise,
+                ] {
+                    let grow_dir = DIRECTIONS[grow_dir_index];
+
+                    let mut this_move_cost = 1;
+                    if grow_dir_index != active_path.direction {
+                        this_move_cost += 1000
+                    }
+                    let new_cost = active_path.cost + this_move_cost;
+
+                    let new_pos = tail + grow_dir;
+
+                    if self.is_wall_or_oob(new_pos) {
+                        continue;
+                    }
+
+                    if let Some((_index, prev_cost)) = traveled_tiles.get(&new_pos) {
+                        // // TODO: potentially steal
+                        // if *index == path_i {
+                        //     // ourselves
+                        //     continue;
+                        // }
+
+                        if *prev_cost < new_cost - 1000 {
+                            // our path is more expensive, don't bother
+                            continue;
+                        }
+
+                        // Only tiles some path already got to can be on ours, so this walk is
+                        // skipped for new ground
+                        if tiles.contains(active_path.path, &new_pos) {
+                            // this path has already been down this road
+                            continue 'OUTER;
+                        }
+                    }
+
+                    traveled_tiles.insert(new_pos, (path_i, new_cost));
+
+                    did_grow = true;
+                    if grow_ourselves.is_none() {
+                        grow_ourselves = Some((new_pos, grow_dir_index, new_cost));
+                    } else {
+                        // Branches share the tiles walked so far instead of copying them
+                        new_paths.push(Path {
+                            path: tiles.extend(active_path.path, new_pos),
+                            cost: new_cost,
+                            direction: grow_dir_index,
+                            complete: false,
+                        });
+                    }
+                }
+
+                if let Some((new_pos, dir, cost)) = grow_ourselves {
+                    active_path.cost = cost;
+                    active_path.direction = dir;
+                    active_path.path = tiles.extend(active_path.path, new_pos);
+                }
+            }
+
+            progress.inc_with(|| format!("{} paths", active_paths.len()));
+            if !did_grow {
+                return Paths {
+                    tiles,
+                    paths: active_paths,
+                };
+            }
+
+            active_paths.append(&mut new_paths);
+        }
+    }
+
+    pub fn is_wall_or_oob(&self, pos: IVec2) -> bool {
+        if !self.is_in_bounds(pos) {
+            return false;
+        }
+
+        self.maze[pos.y as usize][pos.x as usize]
+    }
+
+    pub fn is_in_bounds(&self, pos: IVec2) -> bool {
+        let width = self.maze[0].len() as i64;
+        let height = self.maze.len() as i64;
+
+        pos.x >= 0 && pos.y >= 0 && pos.x < width && pos.y < height
+    }
+}
+
+/// Every path grown by [`ParsedInput::solve_maze`], with the arena holding their tiles.
+#[derive(Debug, Clone)]
+pub struct Paths {
+    pub tiles: PathArena<IVec2>,
+    pub paths: Vec<Path>,
+}
+
+/// A path walked by a reindeer, with the score it took to get there.
+#[derive(Debug, Clone, Copy)]
+pub struct Path {
+    /// The tiles from the start, in [`Paths::tiles`].
+    pub path: PathId,
+    pub cost: u64,
+    /// Index into the right, down, left, up directions.
+    pub direction: usize,
+    /// Set once the path reached the end.
+    pub complete: bool,
+}
+
+pub fn parse_input(input: &str) -> ParsedInput {
+    let parsed = GridParser::new(|c| match c {
+        '.' | 'S' | 'E' => Some(false),
+        '#' => Some(true),
+        _ => None,
+    })
+    .marker('S', Count::ExactlyOne)
+    .marker('E', Count::ExactlyOne)
+    .parse(input)
+    .unwrap_or_else(|e| panic!("bad maze: {e}"));
+
+    ParsedInput {
+        start: parsed.one('S'),
+        end: parsed.one('E'),
+        maze: parsed.grid.rows().map(<[_]>::to_vec).collect(),
+    }
+}
@@ -0,0 +1,21 @@
+use aoc_2024_day_16::{best_tiles, parse_input, part_1, print_maze, EXAMPLE_INPUT, INPUT};
+
+fn main() {
+    common::log::init_from_args();
+
+    let use_example = std::env::args().any(|v| v == "--example");
+
+    println!("Launching, using example. {use_example}");
+
+    let input = common::input::load(if use_example { EXAMPLE_INPUT } else { INPUT });
+    let parsed = parse_input(&input);
+
+    println!("part 1");
+    println!("lowest? {}", part_1(&parsed));
+
+    println!("=======");
+    println!("part 2");
+    let tiles = best_tiles(&parsed);
+    print_maze(&parsed, &tiles);
+    println!("very nice view: {}", tiles.len());
+}
@@ -0,0 +1,12 @@
+use aoc_2024_day_16::{parse_input, EXAMPLE_INPUT};
+use common::IVec2;
+
+#[test]
+fn parses_the_example_literal_as_is() {
+    let parsed = parse_input(EXAMPLE_INPUT);
+
+    assert_eq!(parsed.maze.len(), 17);
+    assert!(parsed.maze.iter().all(|row| row.len() == 17));
+    assert_eq!(parsed.start, IVec2 { x: 1, y: 15 });
+    assert_eq!(parsed.end, IVec2 { x: 15, y: 1 });
+}
@@ -0,0 +1,96 @@
+use std::{collections::HashMap, path::Path};
+
+/// Default location of the checked-in answers for the real inputs.
+pub const DEFAULT_ANSWERS_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/answers.txt");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Star {
+    /// Matches the known answer
+    Earned,
+    /// Differs from the known answer
+    Wrong,
+    /// No known answer to compare against
+    Unknown,
+}
+
+impl Star {
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Star::Earned => "★",
+            Star::Wrong => "✗",
+            Star::Unknown => "☆",
+        }
+    }
+}
+
+/// Known correct answers, keyed by year, day and part.
+///
+/// The file has one `<year> <day> <part> <answer>` entry per line, blank lines and lines starting
+/// with `#` are ignored.
+#[derive(Default)]
+pub struct Answers {
+    answers: HashMap<(u32, u32, u8), String>,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|err| format!("failed reading {}: {err}", path.display()))?;
+
+        Self::parse(&contents).map_err(|err| format!("{}: {err}", path.display()))
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let mut answers = HashMap::new();
+
+        for (i, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut split = line.split_whitespace();
+            let (Some(year), Some(day), Some(part), Some(answer), None) = (
+                split.next(),
+                split.next(),
+                split.next(),
+                split.next(),
+                split.next(),
+            ) else {
+                return Err(format!(
+                    "line {}: expected `<year> <day> <part> <answer>`",
+                    i + 1
+                ));
+            };
+
+            let year = year
+                .parse()
+                .map_err(|_| format!("line {}: bad year {year:?}", i + 1))?;
+            let day = day
+                .parse()
+                .map_err(|_| format!("line {}: bad day {day:?}", i + 1))?;
+            let part = match part {
+                "1" => 1,
+                "2" => 2,
+                _ => return Err(format!("line {}: bad part {part:?}", i + 1)),
+            };
+
+            answers.insert((year, day, part), answer.to_string());
+        }
+
+        Ok(Self { answers })
+    }
+
+    /// Whether there's a known answer for the part.
+    pub fn contains(&self, year: u32, day: u32, part: u8) -> bool {
+        self.answers.contains_key(&(year, day, part))
+    }
+
+    pub fn star(&self, year: u32, day: u32, part: u8, answer: &str) -> Star {
+        match self.answers.get(&(year, day, part)) {
+            Some(known) if known == answer => Star::Earned,
+            Some(_) => Star::Wrong,
+            None => Star::Unknown,
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use std::time::Duration;
+
+use crate::runner::{run_day, DayResult, FallibleAnswer, PartOutput, PlainAnswer};
+
+pub struct Day {
+    pub year: u32,
+    pub day: u32,
+    pub title: &'static str,
+    pub input: &'static str,
+    pub example: Option<&'static str>,
+    /// Parses the given input and runs both parts on it, with an optional time budget per part.
+    pub run: fn(&str, Option<Duration>) -> DayResult,
+    /// Renders the day's simulation after the given number of steps, for days that have one.
+    pub simulate: Option<fn(&str, u64) -> String>,
+}
+
+impl Day {
+    pub fn find(year: u32, day: u32) -> Option<&'static Day> {
+        DAYS.iter().find(|v| v.year == year && v.day == day)
+    }
+
+    /// Directory of the day's crate relative to the workspace root, e.g `cmd/2024/day-07`.
+    pub fn crate_dir(year: u32, day: u32) -> String {
+        format!("cmd/{year}/day-{day:02}")
+    }
+}
+
+macro_rules! day {
+    ($year:literal, $day:literal, $krate:ident, $title:literal) => {
+        day!(@ $year, $day, $krate, $title, None, None)
+    };
+    ($year:literal, $day:literal, $krate:ident, $title:literal, example) => {
+        day!(@ $year, $day, $krate, $title, Some($krate::EXAMPLE_INPUT), None)
+    };
+    ($year:literal, $day:literal, $krate:ident, $title:literal, example, simulate) => {
+        day!(
+            @ $year,
+            $day,
+            $krate,
+            $title,
+            Some($krate::EXAMPLE_INPUT),
+            Some($krate::simulate)
+        )
+    };
+    (@ $year:literal, $day:literal, $krate:ident, $title:literal, $example:expr, $simulate:expr) => {
+        Day {
+            year: $year,
+            day: $day,
+            title: $title,
+            input: $krate::INPUT,
+            example: $example,
+            run: |input, budget| {
+                run_day(
+                    input,
+                    budget,
+                    $krate::parse_input,
+                    |v| (&PartOutput($krate::part_1(v))).answer(),
+                    |v| (&PartOutput($krate::part_2(v))).answer(),
+                )
+            },
+            simulate: $simulate,
+        }
+    };
+}
+
+/// Every solved day, ordered by year and day.
+pub const DAYS: &[Day] = &[
+    day!(2024, 1, aoc_2024_day_01, "Historian Hysteria"),
+    day!(2024, 2, aoc_2024_day_02, "Red-Nosed Reports"),
+    day!(2024, 3, aoc_2024_day_03, "Mull It Over"),
+    day!(2024, 4, aoc_2024_day_04, "Ceres Search"),
+    day!(2024, 5, aoc_2024_day_05, "Print Queue"),
+    day!(2024, 6, aoc_2024_day_06, "Guard Gallivant"),
+    day!(2024, 7, aoc_2024_day_07, "Bridge Repair", example),
+    day!(2024, 8, aoc_2024_day_08, "Resonant Collinearity", example),
+    day!(2024, 9, aoc_2024_day_09, "Disk Fragmenter", example),
+    day!(2024, 10, aoc_2024_day_10, "Hoof It", example),
+    day!(2024, 11, aoc_2024_day_11, "Plutonian Pebbles", example),
+    day!(2024, 12, aoc_2024_day_12, "Garden Groups", example),
+    day!(2024, 13, aoc_2024_day_13, "Claw Contraption", example),
+    day!(
+        2024,
+        14,
+        aoc_2024_day_14,
+        "Restroom Redoubt",
+        example,
+        simulate
+    ),
+    day!(2024, 15, aoc_2024_day_15, "Warehouse Woes", example),
+    day!(2024, 16, aoc_2024_day_16, "Reindeer Maze", example),
+];
@@ -0,0 +1,11 @@
+//! The runner behind the `aoc` binary: every solved day, running and timing them, and the
+//! reports and records written about the results.
+
+pub mod answers;
+pub mod days;
+pub mod records;
+pub mod report;
+pub mod resources;
+pub mod runner;
+pub mod template;
+pub mod watch;
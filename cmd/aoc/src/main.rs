@@ -0,0 +1,317 @@
+use std::{path::PathBuf, process::ExitCode, time::Duration};
+
+use aoc::{
+    answers::{Answers, DEFAULT_ANSWERS_PATH},
+    days::{Day, DAYS},
+    records::part_records,
+    report::{Report, ReportOptions},
+    runner::{format_cost, format_resources, DayResult},
+    template::new_day,
+    watch::{watch, WatchOptions},
+};
+use common::log::{self, Level};
+
+#[cfg(feature = "alloc-counter")]
+#[global_allocator]
+static ALLOCATOR: common::alloc::CountingAllocator = common::alloc::CountingAllocator;
+
+const USAGE: &str = "usage:
+    aoc run [YEAR [DAY]] [--example] [--timeout SECS] [--format text|json]
+    aoc report [YEAR [DAY]] [--example] [--timeout SECS] [--redact] [--markdown PATH]
+               [--html PATH] [--answers PATH] [--vis-dir DIR]
+    aoc watch YEAR DAY [--example] [--timeout SECS] [--release]
+    aoc new YEAR DAY
+    aoc simulate YEAR DAY STEPS [--example]
+
+Every command takes -v or -vv for debug output from the solutions and -q to only show
+errors, the AOC_LOG environment variable (error, warn, info, debug or trace) works too.
+--timeout cancels every part that runs for longer than SECS, the other days still run.
+run --format json prints one JSON object per part and line, see records.rs for the fields.
+report writes report.md when neither --markdown nor --html is given.
+watch runs the day, then again whenever a file in its crate or in cmd/common/src changes.
+new copies cmd/day-template to cmd/YEAR/day-DD.
+simulate prints the state after STEPS steps for days with a simulation, skipping whole cycles
+so e.g 1000000000000000 steps finish as quickly as the first cycle.
+Build with --features alloc-counter to also count allocations per part, and with
+--features bigint for days 7, 11 and 13 to use big integers where their numbers overflow.";
+
+#[derive(Default, PartialEq, Eq)]
+enum Format {
+    #[default]
+    Text,
+    Json,
+}
+
+#[derive(Default)]
+struct Options {
+    year: Option<u32>,
+    day: Option<u32>,
+    steps: Option<u64>,
+    example: bool,
+    redact: bool,
+    release: bool,
+    verbose: u8,
+    quiet: bool,
+    timeout: Option<Duration>,
+    format: Format,
+    markdown: Option<PathBuf>,
+    html: Option<PathBuf>,
+    answers: Option<PathBuf>,
+    vis_dir: Option<PathBuf>,
+}
+
+impl Options {
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let mut options = Options::default();
+        let mut iter = args.iter();
+
+        while let Some(arg) = iter.next() {
+            let mut value = || {
+                iter.next()
+                    .cloned()
+                    .ok_or_else(|| format!("{arg} expects a value"))
+            };
+
+            match arg.as_str() {
+                "--example" => options.example = true,
+                "--redact" => options.redact = true,
+                "--release" => options.release = true,
+                "-v" => options.verbose += 1,
+                "-vv" => options.verbose += 2,
+                "-q" => options.quiet = true,
+                "--timeout" => {
+                    let secs = value()?;
+                    let timeout = secs
+                        .parse()
+                        .ok()
+                        .and_then(|v| Duration::try_from_secs_f64(v).ok())
+                        .ok_or_else(|| format!("bad timeout {secs:?}"))?;
+                    options.timeout = Some(timeout);
+                }
+                "--format" => {
+                    options.format = match value()?.as_str() {
+                        "text" => Format::Text,
+                        "json" => Format::Json,
+                        format => return Err(format!("unknown format {format:?}")),
+                    }
+                }
+                "--markdown" => options.markdown = Some(value()?.into()),
+                "--html" => options.html = Some(value()?.into()),
+                "--answers" => options.answers = Some(value()?.into()),
+                "--vis-dir" => options.vis_dir = Some(value()?.into()),
+                _ if arg.starts_with("--") => return Err(format!("unknown argument {arg:?}")),
+                _ if options.year.is_none() => {
+                    options.year = Some(arg.parse().map_err(|_| format!("bad year {arg:?}"))?);
+                }
+                _ if options.day.is_none() => {
+                    options.day = Some(arg.parse().map_err(|_| format!("bad day {arg:?}"))?);
+                }
+                _ if options.steps.is_none() => {
+                    options.steps = Some(arg.parse().map_err(|_| format!("bad steps {arg:?}"))?);
+                }
+                _ => return Err(format!("unexpected argument {arg:?}")),
+            }
+        }
+
+        if options.verbose > 0 || options.quiet {
+            log::set_level(Level::from_verbosity(options.verbose, options.quiet));
+        }
+
+        Ok(options)
+    }
+
+    /// The `(year, day)` given on the command line, for commands that work on a single day.
+    fn key(&self) -> Result<(u32, u32), String> {
+        match (self.year, self.day) {
+            (Some(year), Some(day)) => Ok((year, day)),
+            _ => Err("expected a YEAR and DAY".to_string()),
+        }
+    }
+
+    /// The days to run along with the input to run them on.
+    fn selected_days(&self) -> Result<Vec<(&'static Day, &'static str)>, String> {
+        let days = match (self.year, self.day) {
+            (Some(year), Some(day)) => vec![find_day(year, day)?],
+            (year, _) => {
+                let days = DAYS
+                    .iter()
+                    .filter(|v| year.is_none_or(|year| v.year == year))
+                    .collect::<Vec<_>>();
+                if let (Some(year), true) = (year, days.is_empty()) {
+                    return Err(format!("no solutions for {year}"));
+                }
+                days
+            }
+        };
+
+        if !self.example {
+            return Ok(days.into_iter().map(|v| (v, v.input)).collect());
+        }
+
+        if let [day] = days[..] {
+            if day.example.is_none() {
+                return Err(format!("{} day {} has no example input", day.year, day.day));
+            }
+        }
+
+        Ok(days
+            .into_iter()
+            .filter_map(|v| Some((v, v.example?)))
+            .collect())
+    }
+}
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+
+    let result = match args.first().map(|v| v.as_str()) {
+        Some("run") => Options::parse(&args[1..]).and_then(|v| run(&v)),
+        Some("report") => Options::parse(&args[1..]).and_then(|v| report(&v)),
+        Some("watch") => Options::parse(&args[1..]).and_then(|v| watch_day(&v)),
+        Some("new") => Options::parse(&args[1..]).and_then(|v| {
+            let (year, day) = v.key()?;
+            new_day(year, day)
+        }),
+        Some("simulate") => Options::parse(&args[1..]).and_then(|v| simulate(&v)),
+        _ => Err(USAGE.to_string()),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run_selected(options: &Options) -> Result<Vec<(&'static Day, DayResult)>, String> {
+    Ok(options
+        .selected_days()?
+        .into_iter()
+        .map(|(day, input)| (day, (day.run)(input, options.timeout)))
+        .collect())
+}
+
+fn run(options: &Options) -> Result<(), String> {
+    for (day, result) in run_selected(options)? {
+        if options.format == Format::Json {
+            for record in part_records(day, input_name(options), &result) {
+                println!("{record}");
+            }
+            continue;
+        }
+
+        match &result.parse.result {
+            Ok(()) => println!(
+                "{} Day {:02}: {} (parse {})",
+                day.year,
+                day.day,
+                day.title,
+                format_cost(&result.parse)
+            ),
+            Err(err) => println!(
+                "{} Day {:02}: {} (parse failed: {err})",
+                day.year, day.day, day.title
+            ),
+        }
+
+        for (i, part) in result.parts.iter().enumerate() {
+            let Some(part) = part else {
+                continue;
+            };
+
+            match &part.result {
+                Ok(answer) => println!("  part {}: {answer} ({})", i + 1, format_cost(part)),
+                Err(err) => println!("  part {}: failed: {err}", i + 1),
+            }
+        }
+
+        if let Some(usage) = result.resources() {
+            println!("  {}", format_resources(&usage));
+        }
+    }
+
+    Ok(())
+}
+
+fn report(options: &Options) -> Result<(), String> {
+    let answers = match (&options.answers, options.example) {
+        (_, true) => None,
+        (Some(path), false) => Some(Answers::load(path)?),
+        (None, false) => Some(Answers::load(DEFAULT_ANSWERS_PATH.as_ref())?),
+    };
+
+    let results = run_selected(options)?;
+    let report = Report::new(
+        &results,
+        &ReportOptions {
+            redact: options.redact,
+            answers: answers.as_ref(),
+            vis_dir: options.vis_dir.as_deref().unwrap_or("vis".as_ref()),
+            input_name: input_name(options),
+        },
+    );
+
+    let markdown = match (&options.markdown, &options.html) {
+        (None, None) => Some(PathBuf::from("report.md")),
+        (markdown, _) => markdown.clone(),
+    };
+
+    if let Some(path) = markdown {
+        write_file(&path, &report.to_markdown(&path))?;
+    }
+
+    if let Some(path) = &options.html {
+        write_file(path, &report.to_html(path))?;
+    }
+
+    Ok(())
+}
+
+fn input_name(options: &Options) -> &'static str {
+    if options.example {
+        "example"
+    } else {
+        "real"
+    }
+}
+
+fn write_file(path: &std::path::Path, contents: &str) -> Result<(), String> {
+    std::fs::write(path, contents)
+        .map_err(|err| format!("failed writing {}: {err}", path.display()))?;
+    eprintln!("Wrote {}", path.display());
+    Ok(())
+}
+
+fn find_day(year: u32, day: u32) -> Result<&'static Day, String> {
+    Day::find(year, day).ok_or_else(|| format!("no solution for {year} day {day}"))
+}
+
+fn simulate(options: &Options) -> Result<(), String> {
+    options.key()?;
+    let steps = options.steps.ok_or("expected the number of STEPS")?;
+    let [(day, input)] = options.selected_days()?[..] else {
+        unreachable!("YEAR and DAY select a single day");
+    };
+    let simulate = day
+        .simulate
+        .ok_or_else(|| format!("{} day {} has no simulation", day.year, day.day))?;
+
+    print!("{}", simulate(input, steps));
+    Ok(())
+}
+
+fn watch_day(options: &Options) -> Result<(), String> {
+    let (year, day) = options.key()?;
+    let day = find_day(year, day)?;
+
+    watch(
+        day,
+        &WatchOptions {
+            example: options.example,
+            release: options.release,
+            timeout: options.timeout,
+        },
+    )
+}
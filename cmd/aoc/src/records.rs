@@ -0,0 +1,93 @@
+//! One JSON object per part for `aoc run --format json`, written as JSON Lines.
+//!
+//! Every record has these keys, in this order:
+//!
+//! - `year`, `day`, `part`: integers identifying the part
+//! - `input`: `"real"` or `"example"`
+//! - `status`: `"ok"`, `"error"`, `"timeout"` or `"not_implemented"`
+//! - `answer`: the answer as a string, `null` unless the status is `"ok"`
+//! - `error`: what went wrong, `null` when the status is `"ok"`
+//! - `parse_ns`: time spent parsing the input in nanoseconds
+//! - `elapsed_ns`: time spent on the part in nanoseconds, `null` if parsing failed
+//! - `allocations`: `{"count", "bytes", "peak_bytes"}` of the part, `null` unless built with the
+//!   `alloc-counter` feature or if parsing failed
+//! - `peak_rss_bytes`, `cpu_ns`: peak RSS and CPU time of the part, `null` when they can't be
+//!   sampled or if parsing failed
+//!
+//! A failed parse fails both parts with its status, the error is prefixed with `parse failed: `.
+
+use common::json::Json;
+
+use crate::{
+    days::Day,
+    runner::{DayResult, Failure, Measurement},
+};
+
+pub fn part_records(day: &Day, input_name: &str, result: &DayResult) -> Vec<Json> {
+    (0..2)
+        .map(|i| {
+            let (status, answer, error) = match (&result.parse.result, &result.parts[i]) {
+                (Err(failure), _) => (
+                    status(failure),
+                    None,
+                    Some(format!("parse failed: {failure}")),
+                ),
+                (Ok(()), Some(part)) => match &part.result {
+                    Ok(answer) => ("ok", Some(answer.clone()), None),
+                    Err(failure) => (status(failure), None, Some(failure.to_string())),
+                },
+                (Ok(()), None) => unreachable!("parts always run after a successful parse"),
+            };
+            let part = result.parts[i].as_ref();
+
+            Json::object([
+                ("year", day.year.into()),
+                ("day", day.day.into()),
+                ("part", (i + 1).into()),
+                ("input", input_name.into()),
+                ("status", status.into()),
+                ("answer", answer.into()),
+                ("error", error.into()),
+                ("parse_ns", nanos(result.parse.elapsed)),
+                ("elapsed_ns", part.map_or(Json::Null, |v| nanos(v.elapsed))),
+                ("allocations", part.map_or(Json::Null, allocations)),
+                (
+                    "peak_rss_bytes",
+                    part.and_then(|v| v.resources).map(|v| v.peak_rss).into(),
+                ),
+                (
+                    "cpu_ns",
+                    part.and_then(|v| v.resources)
+                        .map_or(Json::Null, |v| nanos(v.cpu_time())),
+                ),
+            ])
+        })
+        .collect()
+}
+
+fn status(failure: &Failure) -> &'static str {
+    match failure {
+        Failure::Panicked(_) | Failure::Failed(_) => "error",
+        Failure::TimedOut(_) => "timeout",
+        Failure::NotImplemented(_) => "not_implemented",
+    }
+}
+
+fn nanos(duration: std::time::Duration) -> Json {
+    Json::Int(duration.as_nanos() as i128)
+}
+
+#[cfg(feature = "alloc-counter")]
+fn allocations<T>(measurement: &Measurement<T>) -> Json {
+    let allocs = &measurement.allocs;
+    Json::object([
+        ("count", allocs.allocations.into()),
+        ("bytes", allocs.bytes_allocated.into()),
+        ("peak_bytes", allocs.peak_live_bytes.into()),
+    ])
+}
+
+#[cfg(not(feature = "alloc-counter"))]
+fn allocations<T>(_measurement: &Measurement<T>) -> Json {
+    Json::Null
+}
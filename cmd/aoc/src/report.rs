@@ -0,0 +1,428 @@
+use std::{
+    fmt::Write,
+    path::{Component, Path, PathBuf},
+    time::Duration,
+};
+
+use crate::{
+    answers::{Answers, Star},
+    days::Day,
+    resources::ResourceUsage,
+    runner::{format_bytes, format_duration, DayResult},
+};
+
+pub struct ReportOptions<'a> {
+    /// Hide the answers, only showing whether they earned a star.
+    pub redact: bool,
+    /// Known answers to award stars with, `None` when running on example inputs.
+    pub answers: Option<&'a Answers>,
+    pub vis_dir: &'a Path,
+    pub input_name: &'a str,
+}
+
+struct Row {
+    year: u32,
+    day: u32,
+    title: &'static str,
+    parse: Duration,
+    parse_error: Option<String>,
+    parts: [PartCell; 2],
+    resources: Option<ResourceUsage>,
+    visualisations: Vec<PathBuf>,
+}
+
+struct PartCell {
+    answer: Option<Result<String, String>>,
+    elapsed: Option<Duration>,
+    star: Star,
+}
+
+pub struct Report {
+    rows: Vec<Row>,
+    redact: bool,
+    input_name: String,
+}
+
+impl Report {
+    pub fn new(results: &[(&Day, DayResult)], options: &ReportOptions) -> Self {
+        let rows = results
+            .iter()
+            .map(|(day, result)| {
+                let parts = [0, 1].map(|i| {
+                    let Some(measurement) = &result.parts[i] else {
+                        return PartCell {
+                            answer: None,
+                            elapsed: None,
+                            star: Star::Unknown,
+                        };
+                    };
+
+                    let star = match (&measurement.result, options.answers) {
+                        (Ok(answer), Some(answers)) => {
+                            answers.star(day.year, day.day, i as u8 + 1, answer)
+                        }
+                        // A part that failed only counts as wrong when it had an answer to get
+                        (Err(_), Some(answers))
+                            if answers.contains(day.year, day.day, i as u8 + 1) =>
+                        {
+                            Star::Wrong
+                        }
+                        (Err(_), Some(_)) | (_, None) => Star::Unknown,
+                    };
+
+                    PartCell {
+                        answer: Some(measurement.result.clone().map_err(|v| v.to_string())),
+                        elapsed: Some(measurement.elapsed),
+                        star,
+                    }
+                });
+
+                Row {
+                    year: day.year,
+                    day: day.day,
+                    title: day.title,
+                    parse: result.parse.elapsed,
+                    parse_error: result.parse.result.as_ref().err().map(|v| v.to_string()),
+                    parts,
+                    resources: result.resources(),
+                    visualisations: find_visualisations(options.vis_dir, day.year, day.day),
+                }
+            })
+            .collect();
+
+        Self {
+            rows,
+            redact: options.redact,
+            input_name: options.input_name.to_string(),
+        }
+    }
+
+    /// Heading of the report, e.g `Advent of Code 2023, 2024`.
+    fn title(&self) -> String {
+        let mut years = self.rows.iter().map(|v| v.year).collect::<Vec<_>>();
+        years.dedup();
+
+        let years = years
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("Advent of Code {years}")
+    }
+
+    fn earned_stars(&self) -> usize {
+        self.rows
+            .iter()
+            .flat_map(|v| &v.parts)
+            .filter(|v| v.star == Star::Earned)
+            .count()
+    }
+
+    fn total_elapsed(&self) -> Duration {
+        self.rows
+            .iter()
+            .map(|v| v.parse + v.parts.iter().flat_map(|v| v.elapsed).sum::<Duration>())
+            .sum()
+    }
+
+    fn answer_text(&self, cell: &PartCell) -> String {
+        match &cell.answer {
+            None => "-".to_string(),
+            Some(Err(err)) => format!("error: {}", err.replace('\n', " ")),
+            Some(Ok(_)) if self.redact => "(redacted)".to_string(),
+            Some(Ok(answer)) => answer.clone(),
+        }
+    }
+
+    /// Peak RSS and CPU time cells, `-` when they couldn't be sampled.
+    fn resource_text(row: &Row) -> [String; 2] {
+        match &row.resources {
+            Some(usage) => [
+                format_bytes(usage.peak_rss),
+                format_duration(usage.cpu_time()),
+            ],
+            None => ["-".to_string(), "-".to_string()],
+        }
+    }
+
+    /// The report as a markdown table, `path` is where it gets written so the visualisation
+    /// links can be relative to it.
+    pub fn to_markdown(&self, path: &Path) -> String {
+        let dir = parent_dir(path);
+        let mut out = String::new();
+
+        writeln!(out, "# {}", self.title()).unwrap();
+        writeln!(out).unwrap();
+        writeln!(
+            out,
+            "Using the {} inputs, {} of {} stars in {}.",
+            self.input_name,
+            self.earned_stars(),
+            self.rows.len() * 2,
+            format_duration(self.total_elapsed())
+        )
+        .unwrap();
+        writeln!(out).unwrap();
+        writeln!(
+            out,
+            "| Year | Day | Title | Parse | Part 1 | Time | Part 2 | Time | Peak RSS | CPU | Stars | Visualisations |"
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "|---:|---:|---|---:|---|---:|---|---:|---:|---:|:---:|---|"
+        )
+        .unwrap();
+
+        for row in &self.rows {
+            let parse = match &row.parse_error {
+                Some(err) => format!("error: {}", err.replace('\n', " ")),
+                None => format_duration(row.parse),
+            };
+
+            let mut cells = vec![
+                row.year.to_string(),
+                row.day.to_string(),
+                row.title.to_string(),
+                markdown_escape(&parse),
+            ];
+            for part in &row.parts {
+                cells.push(markdown_escape(&self.answer_text(part)));
+                cells.push(part.elapsed.map(format_duration).unwrap_or("-".to_string()));
+            }
+            cells.extend(Self::resource_text(row));
+            cells.push(row.parts.iter().map(|v| v.star.symbol()).collect());
+            cells.push(
+                row.visualisations
+                    .iter()
+                    .map(|v| format!("[{}]({})", markdown_escape(&file_name(v)), link(v, dir)))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            );
+
+            writeln!(out, "| {} |", cells.join(" | ")).unwrap();
+        }
+
+        out
+    }
+
+    /// The report as a standalone HTML page, `path` is where it gets written so the
+    /// visualisation links can be relative to it.
+    pub fn to_html(&self, path: &Path) -> String {
+        let dir = parent_dir(path);
+        let mut out = String::new();
+
+        out.push_str(concat!(
+            "<!DOCTYPE html>\n",
+            "<html lang=\"en\">\n",
+            "<head>\n",
+            "<meta charset=\"utf-8\">\n",
+        ));
+        writeln!(out, "<title>{}</title>", html_escape(&self.title())).unwrap();
+        out.push_str(concat!(
+            "<style>\n",
+            "body { font-family: sans-serif; margin: 2em; background: #0f0f23; color: #cccccc; }\n",
+            "h1 { color: #00cc00; }\n",
+            "table { border-collapse: collapse; }\n",
+            "th, td { padding: 0.3em 0.8em; border-bottom: 1px solid #333340; text-align: left; }\n",
+            "td.num { text-align: right; font-family: monospace; }\n",
+            "td.answer { font-family: monospace; white-space: pre; }\n",
+            "td.error { color: #ff5555; }\n",
+            "td.stars { color: #ffff66; text-align: center; }\n",
+            "a { color: #009900; }\n",
+            "</style>\n",
+            "</head>\n",
+            "<body>\n",
+        ));
+        writeln!(out, "<h1>{}</h1>", html_escape(&self.title())).unwrap();
+
+        writeln!(
+            out,
+            "<p>Using the {} inputs, {} of {} stars in {}.</p>",
+            html_escape(&self.input_name),
+            self.earned_stars(),
+            self.rows.len() * 2,
+            format_duration(self.total_elapsed())
+        )
+        .unwrap();
+
+        out.push_str("<table>\n<tr><th>Year</th><th>Day</th><th>Title</th><th>Parse</th><th>Part 1</th><th>Time</th><th>Part 2</th><th>Time</th><th>Peak RSS</th><th>CPU</th><th>Stars</th><th>Visualisations</th></tr>\n");
+
+        for row in &self.rows {
+            out.push_str("<tr>");
+            write!(out, "<td class=\"num\">{}</td>", row.year).unwrap();
+            write!(out, "<td class=\"num\">{}</td>", row.day).unwrap();
+            write!(out, "<td>{}</td>", html_escape(row.title)).unwrap();
+            match &row.parse_error {
+                Some(err) => write!(out, "<td class=\"error\">error: {}</td>", html_escape(err)),
+                None => write!(out, "<td class=\"num\">{}</td>", format_duration(row.parse)),
+            }
+            .unwrap();
+
+            for part in &row.parts {
+                let class = match &part.answer {
+                    Some(Err(_)) => "answer error",
+                    _ => "answer",
+                };
+                write!(
+                    out,
+                    "<td class=\"{class}\">{}</td>",
+                    html_escape(&self.answer_text(part))
+                )
+                .unwrap();
+                write!(
+                    out,
+                    "<td class=\"num\">{}</td>",
+                    part.elapsed.map(format_duration).unwrap_or("-".to_string())
+                )
+                .unwrap();
+            }
+
+            for cell in Self::resource_text(row) {
+                write!(out, "<td class=\"num\">{cell}</td>").unwrap();
+            }
+
+            let stars: String = row.parts.iter().map(|v| v.star.symbol()).collect();
+            write!(out, "<td class=\"stars\">{stars}</td>").unwrap();
+
+            let links = row
+                .visualisations
+                .iter()
+                .map(|v| {
+                    format!(
+                        "<a href=\"{}\">{}</a>",
+                        html_escape(&link(v, dir)),
+                        html_escape(&file_name(v))
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            writeln!(out, "<td>{links}</td></tr>").unwrap();
+        }
+
+        out.push_str("</table>\n</body>\n</html>\n");
+        out
+    }
+}
+
+/// Files in `dir/<year>` exported for the given day, named `day-XX` followed by anything but a
+/// digit.
+pub fn find_visualisations(dir: &Path, year: u32, day: u32) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir.join(year.to_string())) else {
+        return Vec::new();
+    };
+
+    let prefix = format!("day-{day:02}");
+    let mut found = entries
+        .flatten()
+        .map(|v| v.path())
+        .filter(|v| {
+            let name = file_name(v);
+            name.strip_prefix(&prefix)
+                .is_some_and(|rest| !rest.starts_with(|c: char| c.is_ascii_digit()))
+        })
+        .collect::<Vec<_>>();
+
+    found.sort();
+    found
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|v| v.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+/// Directory of the file at `path`, `.` for a bare file name.
+fn parent_dir(path: &Path) -> &Path {
+    path.parent()
+        .filter(|v| !v.as_os_str().is_empty())
+        .unwrap_or(Path::new("."))
+}
+
+/// URL of `path` relative to the directory `from`, with everything but unreserved characters
+/// and `/` percent-encoded.
+fn link(path: &Path, from: &Path) -> String {
+    let relative = relative_path(path, from)
+        .to_string_lossy()
+        .replace('\\', "/");
+
+    let mut out = String::with_capacity(relative.len());
+    for b in relative.bytes() {
+        if b.is_ascii_alphanumeric() || b"-._~/".contains(&b) {
+            out.push(b as char);
+        } else {
+            write!(out, "%{b:02X}").unwrap();
+        }
+    }
+    out
+}
+
+/// `path` relative to the directory `from`, going up with `..` where they part.
+fn relative_path(path: &Path, from: &Path) -> PathBuf {
+    let (path, from) = (normalized(path), normalized(from));
+    let common = path
+        .components()
+        .zip(from.components())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let mut out = PathBuf::new();
+    for _ in from.components().skip(common) {
+        out.push("..");
+    }
+    for component in path.components().skip(common) {
+        out.push(component);
+    }
+    out
+}
+
+/// `path` made absolute with `.` and `..` resolved, without looking at the filesystem.
+fn normalized(path: &Path) -> PathBuf {
+    let absolute = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+
+    let mut out = PathBuf::new();
+    for component in absolute.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                out.pop();
+            }
+            component => out.push(component),
+        }
+    }
+    out
+}
+
+/// Escapes what markdown would format or take as the end of the table cell, line breaks become
+/// `<br>` so multi-line answers stay in their row.
+fn markdown_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' | '|' | '*' | '_' | '`' | '[' | ']' | '<' | '>' | '#' | '~' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '\n' => out.push_str("<br>"),
+            '\r' => {}
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+fn html_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
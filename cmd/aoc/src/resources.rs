@@ -0,0 +1,121 @@
+//! Memory and CPU usage of the whole process, read from `/proc/self/status` and `getrusage`.
+//! Only implemented for Linux, everywhere else nothing gets sampled.
+
+use std::time::Duration;
+
+/// What a step cost beyond its wall clock time.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ResourceUsage {
+    /// Highest resident set size reached, in bytes.
+    pub peak_rss: u64,
+    pub user_time: Duration,
+    pub system_time: Duration,
+}
+
+impl ResourceUsage {
+    /// Usage of two steps run one after the other.
+    pub fn combine(self, other: Self) -> Self {
+        Self {
+            peak_rss: self.peak_rss.max(other.peak_rss),
+            user_time: self.user_time + other.user_time,
+            system_time: self.system_time + other.system_time,
+        }
+    }
+
+    pub fn cpu_time(&self) -> Duration {
+        self.user_time + self.system_time
+    }
+}
+
+/// Collects the usage between [`ResourceScope::start`] and [`ResourceScope::usage`].
+pub struct ResourceScope {
+    user_time: Duration,
+    system_time: Duration,
+}
+
+impl ResourceScope {
+    /// Resets the peak RSS so that it only covers what follows, returns `None` if the usage
+    /// can't be sampled on this platform.
+    pub fn start() -> Option<Self> {
+        reset_peak_rss();
+        let (user_time, system_time) = cpu_times()?;
+        Some(Self {
+            user_time,
+            system_time,
+        })
+    }
+
+    pub fn usage(&self) -> Option<ResourceUsage> {
+        let (user_time, system_time) = cpu_times()?;
+        let status = std::fs::read_to_string("/proc/self/status").ok()?;
+
+        // When resetting the high water mark isn't allowed it stays at the peak of the whole
+        // process, which is still an upper bound
+        let peak_rss = status_kib(&status, "VmHWM:")?.max(status_kib(&status, "VmRSS:")?);
+
+        Some(ResourceUsage {
+            peak_rss: peak_rss * 1024,
+            user_time: user_time.saturating_sub(self.user_time),
+            system_time: system_time.saturating_sub(self.system_time),
+        })
+    }
+}
+
+/// Reads a line such as `VmHWM:    1320 kB` from `/proc/self/status`, in KiB.
+fn status_kib(status: &str, key: &str) -> Option<u64> {
+    let line = status.lines().find(|v| v.starts_with(key))?;
+    line[key.len()..]
+        .trim()
+        .strip_suffix("kB")?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// Writing 5 to `clear_refs` resets VmHWM to the current RSS, see proc(5).
+fn reset_peak_rss() {
+    let _ = std::fs::write("/proc/self/clear_refs", "5");
+}
+
+#[cfg(target_os = "linux")]
+fn cpu_times() -> Option<(Duration, Duration)> {
+    use std::ffi::{c_int, c_long};
+
+    #[repr(C)]
+    struct Timeval {
+        tv_sec: c_long,
+        tv_usec: c_long,
+    }
+
+    #[repr(C)]
+    struct Rusage {
+        ru_utime: Timeval,
+        ru_stime: Timeval,
+        // maxrss and the other counters, unused
+        _rest: [c_long; 14],
+    }
+
+    extern "C" {
+        fn getrusage(who: c_int, usage: *mut Rusage) -> c_int;
+    }
+
+    const RUSAGE_SELF: c_int = 0;
+
+    let mut usage = std::mem::MaybeUninit::<Rusage>::uninit();
+    // SAFETY: getrusage only writes to the struct, which matches the layout from sys/resource.h
+    if unsafe { getrusage(RUSAGE_SELF, usage.as_mut_ptr()) } != 0 {
+        return None;
+    }
+    // SAFETY: initialised by the successful call above
+    let usage = unsafe { usage.assume_init() };
+
+    let duration = |v: &Timeval| {
+        Duration::from_secs(v.tv_sec as u64) + Duration::from_micros(v.tv_usec as u64)
+    };
+    Some((duration(&usage.ru_utime), duration(&usage.ru_stime)))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn cpu_times() -> Option<(Duration, Duration)> {
+    None
+}
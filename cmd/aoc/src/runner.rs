@@ -0,0 +1,272 @@
+use std::{
+    any::Any,
+    fmt::{self, Display},
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::mpsc::{self, RecvTimeoutError},
+    time::{Duration, Instant},
+};
+
+use common::cancel::{self, CancelToken, Cancelled};
+
+use crate::resources::{ResourceScope, ResourceUsage};
+
+/// How long a step took and what it produced, or why it didn't produce anything.
+pub struct Measurement<T> {
+    pub elapsed: Duration,
+    pub result: Result<T, Failure>,
+    /// Peak RSS and CPU time of the step, `None` when they can't be sampled.
+    pub resources: Option<ResourceUsage>,
+    /// Allocations made during the step, only tracked with the `alloc-counter` feature.
+    #[cfg(feature = "alloc-counter")]
+    pub allocs: common::alloc::AllocStats,
+}
+
+#[derive(Debug, Clone)]
+pub enum Failure {
+    /// Panicked with the given message.
+    Panicked(String),
+    /// Returned an error with the given message.
+    Failed(String),
+    /// Got cancelled after using up its time budget.
+    TimedOut(Duration),
+    /// Hit a `todo!()` or `unimplemented!()`, with the panic message.
+    NotImplemented(String),
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Panicked(message)
+            | Failure::Failed(message)
+            | Failure::NotImplemented(message) => f.write_str(message),
+            Failure::TimedOut(elapsed) => {
+                write!(f, "timed out after {}", format_duration(*elapsed))
+            }
+        }
+    }
+}
+
+pub struct DayResult {
+    pub parse: Measurement<()>,
+    /// Both parts, `None` if parsing the input failed.
+    pub parts: [Option<Measurement<String>>; 2],
+}
+
+impl DayResult {
+    /// Peak RSS and CPU time over parsing and both parts.
+    pub fn resources(&self) -> Option<ResourceUsage> {
+        let parts = self.parts.iter().flatten().map(|v| v.resources);
+        std::iter::once(self.parse.resources)
+            .chain(parts)
+            .reduce(|a, b| Some(a?.combine(b?)))
+            .flatten()
+    }
+}
+
+/// What a part returned, for turning it into its answer with `(&PartOutput(v)).answer()`.
+///
+/// Parts return anything that's `Display`, or a `Result` whose `Err` fails the part. Method
+/// resolution tries [`FallibleAnswer`] on `&PartOutput<_>` before auto-referencing to reach
+/// [`PlainAnswer`], so this only picks the right one for concrete types, like in `day!`.
+pub struct PartOutput<T>(pub T);
+
+pub trait FallibleAnswer {
+    fn answer(&self) -> Result<String, String>;
+}
+
+impl<T: Display, E: Display> FallibleAnswer for PartOutput<Result<T, E>> {
+    fn answer(&self) -> Result<String, String> {
+        match &self.0 {
+            Ok(answer) => Ok(answer.to_string()),
+            Err(err) => Err(err.to_string()),
+        }
+    }
+}
+
+pub trait PlainAnswer {
+    fn answer(&self) -> Result<String, String>;
+}
+
+impl<T: Display> PlainAnswer for &PartOutput<T> {
+    fn answer(&self) -> Result<String, String> {
+        Ok(self.0.to_string())
+    }
+}
+
+/// Parses the input and runs both parts on it, timing each step on its own.
+///
+/// Every step gets cancelled once it runs for longer than `budget`. A part returning `Err` fails
+/// with [`Failure::Failed`].
+pub fn run_day<P>(
+    input: &str,
+    budget: Option<Duration>,
+    parse: impl FnOnce(&str) -> P,
+    part_1: impl FnOnce(&P) -> Result<String, String>,
+    part_2: impl FnOnce(&P) -> Result<String, String>,
+) -> DayResult {
+    let input = common::input::load(input);
+    let parsed = measure(budget, || parse(&input));
+    let Ok(parsed_input) = &parsed.result else {
+        return DayResult {
+            parse: Measurement {
+                elapsed: parsed.elapsed,
+                result: parsed.result.map(|_| ()),
+                resources: parsed.resources,
+                #[cfg(feature = "alloc-counter")]
+                allocs: parsed.allocs,
+            },
+            parts: [None, None],
+        };
+    };
+
+    let part_1 = failed_on_err(measure(budget, || part_1(parsed_input)));
+    let part_2 = failed_on_err(measure(budget, || part_2(parsed_input)));
+
+    DayResult {
+        parse: Measurement {
+            elapsed: parsed.elapsed,
+            result: Ok(()),
+            resources: parsed.resources,
+            #[cfg(feature = "alloc-counter")]
+            allocs: parsed.allocs,
+        },
+        parts: [Some(part_1), Some(part_2)],
+    }
+}
+
+fn failed_on_err(measurement: Measurement<Result<String, String>>) -> Measurement<String> {
+    Measurement {
+        elapsed: measurement.elapsed,
+        result: measurement.result.and_then(|v| v.map_err(Failure::Failed)),
+        resources: measurement.resources,
+        #[cfg(feature = "alloc-counter")]
+        allocs: measurement.allocs,
+    }
+}
+
+fn measure<T>(budget: Option<Duration>, f: impl FnOnce() -> T) -> Measurement<T> {
+    let token = CancelToken::new();
+    let watchdog = budget.map(|v| spawn_watchdog(&token, v));
+
+    #[cfg(feature = "alloc-counter")]
+    let alloc_scope = common::alloc::AllocScope::start();
+    let resource_scope = ResourceScope::start();
+    let started = Instant::now();
+    let result = catch_unwind(AssertUnwindSafe(|| cancel::with_token(&token, f)));
+    let elapsed = started.elapsed();
+    let resources = resource_scope.and_then(|v| v.usage());
+    drop(watchdog);
+
+    Measurement {
+        elapsed,
+        resources,
+        #[cfg(feature = "alloc-counter")]
+        allocs: alloc_scope.stats(),
+        result: result.map_err(|err| failure(&*err, elapsed)),
+    }
+}
+
+/// Cancels `token` after `budget` unless the returned sender gets dropped first, right away when
+/// `budget` is zero.
+fn spawn_watchdog(token: &CancelToken, budget: Duration) -> mpsc::Sender<()> {
+    let (sender, receiver) = mpsc::channel::<()>();
+    if budget.is_zero() {
+        // Without waiting on the thread a quick step could finish before it gets cancelled
+        token.cancel();
+        return sender;
+    }
+
+    let token = token.clone();
+    std::thread::spawn(move || {
+        if let Err(RecvTimeoutError::Timeout) = receiver.recv_timeout(budget) {
+            token.cancel();
+        }
+    });
+    sender
+}
+
+fn failure(payload: &(dyn Any + Send), elapsed: Duration) -> Failure {
+    if payload.is::<Cancelled>() {
+        return Failure::TimedOut(elapsed);
+    }
+
+    let message = if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "panicked".to_string()
+    };
+
+    // The messages of `todo!()` and `unimplemented!()`, which may be followed by `: <reason>`
+    let not_implemented = ["not yet implemented", "not implemented"]
+        .iter()
+        .any(|v| message == *v || message.starts_with(&format!("{v}: ")));
+    if not_implemented {
+        Failure::NotImplemented(message)
+    } else {
+        Failure::Panicked(message)
+    }
+}
+
+/// Formats a byte count with a binary unit, e.g `1.50KiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes}B");
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.2}{}", UNITS[unit])
+}
+
+/// Formats a duration with a unit that keeps the number short, e.g `1.23ms`.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{nanos}ns")
+    } else if nanos < 1_000_000 {
+        format!("{:.2}µs", nanos as f64 / 1_000.0)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2}ms", nanos as f64 / 1_000_000.0)
+    } else {
+        format!("{:.2}s", duration.as_secs_f64())
+    }
+}
+
+/// Timing of a step followed by its allocations when they're being counted, e.g
+/// `1.23ms, 12 allocs, 1.50KiB allocated, 1.00KiB peak`.
+pub fn format_cost<T>(measurement: &Measurement<T>) -> String {
+    let elapsed = format_duration(measurement.elapsed);
+
+    #[cfg(feature = "alloc-counter")]
+    {
+        let allocs = &measurement.allocs;
+        format!(
+            "{elapsed}, {} allocs, {} allocated, {} peak",
+            allocs.allocations,
+            format_bytes(allocs.bytes_allocated),
+            format_bytes(allocs.peak_live_bytes)
+        )
+    }
+
+    #[cfg(not(feature = "alloc-counter"))]
+    elapsed
+}
+
+/// Peak RSS and CPU time, e.g `peak RSS 12.00MiB, CPU 1.20s (user 1.10s, system 100.00ms)`.
+pub fn format_resources(usage: &ResourceUsage) -> String {
+    format!(
+        "peak RSS {}, CPU {} (user {}, system {})",
+        format_bytes(usage.peak_rss),
+        format_duration(usage.cpu_time()),
+        format_duration(usage.user_time),
+        format_duration(usage.system_time)
+    )
+}
@@ -0,0 +1,69 @@
+use std::path::Path;
+
+use crate::{days::Day, watch::WORKSPACE_DIR};
+
+const TEMPLATE_DIR: &str = "cmd/day-template";
+
+/// Copies the day template into the crate for the given day, naming it after the year and day so
+/// solutions from different years don't clash.
+pub fn new_day(year: u32, day: u32) -> Result<(), String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("bad day {day}, expected 1 to 25"));
+    }
+
+    let workspace = Path::new(WORKSPACE_DIR);
+    let crate_dir = Day::crate_dir(year, day);
+    let dest = workspace.join(&crate_dir);
+    if dest.exists() {
+        return Err(format!("{crate_dir} already exists"));
+    }
+
+    let package = format!("aoc-{year}-day-{day:02}");
+    let krate = package.replace('-', "_");
+    copy_template(&workspace.join(TEMPLATE_DIR), &dest, &|contents| {
+        contents
+            .replace("name = \"day-template\"", &format!("name = \"{package}\""))
+            .replace("path = \"../common\"", "path = \"../../common\"")
+            .replace("day_template::", &format!("{krate}::"))
+    })?;
+
+    println!("Created {crate_dir}. To run it with aoc, add");
+    println!("    {package} = {{ path = \"../{year}/day-{day:02}\" }}");
+    println!("to the dependencies in cmd/aoc/Cargo.toml and");
+    println!("    day!({year}, {day}, {krate}, \"Title\"),");
+    println!("to DAYS in cmd/aoc/src/days.rs.");
+
+    Ok(())
+}
+
+/// Copies `src` to `dest` recursively, passing Rust and TOML files through `rewrite`.
+fn copy_template(src: &Path, dest: &Path, rewrite: &dyn Fn(&str) -> String) -> Result<(), String> {
+    std::fs::create_dir_all(dest)
+        .map_err(|err| format!("failed creating {}: {err}", dest.display()))?;
+
+    let entries =
+        std::fs::read_dir(src).map_err(|err| format!("failed reading {}: {err}", src.display()))?;
+    for entry in entries {
+        let entry = entry.map_err(|err| format!("failed reading {}: {err}", src.display()))?;
+        let from = entry.path();
+        let to = dest.join(entry.file_name());
+
+        if from.is_dir() {
+            copy_template(&from, &to, rewrite)?;
+            continue;
+        }
+
+        let rewritten = matches!(
+            from.extension().and_then(|v| v.to_str()),
+            Some("rs" | "toml")
+        );
+        let result = if rewritten {
+            std::fs::read_to_string(&from).and_then(|v| std::fs::write(&to, rewrite(&v)))
+        } else {
+            std::fs::copy(&from, &to).map(|_| ())
+        };
+        result.map_err(|err| format!("failed copying {}: {err}", from.display()))?;
+    }
+
+    Ok(())
+}
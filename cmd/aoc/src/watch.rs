@@ -0,0 +1,214 @@
+use std::{
+    collections::HashMap,
+    ffi::OsString,
+    path::{Path, PathBuf},
+    process::Command,
+    thread::sleep,
+    time::{Duration, SystemTime},
+};
+
+use common::json::Json;
+
+use crate::{days::Day, runner::format_duration};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Root of the cargo workspace, which is where cargo gets invoked from.
+pub const WORKSPACE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../..");
+
+pub struct WatchOptions {
+    pub example: bool,
+    pub release: bool,
+    pub timeout: Option<Duration>,
+}
+
+/// Shared code every day builds against, watched along with the day's own crate.
+const COMMON_DIR: &str = "cmd/common/src";
+
+/// Rebuilds and re-runs the day once and then every time a file in its crate or in
+/// [`COMMON_DIR`] changes, never returns.
+pub fn watch(day: &Day, options: &WatchOptions) -> ! {
+    let workspace = Path::new(WORKSPACE_DIR);
+    let dirs = [
+        workspace.join(Day::crate_dir(day.year, day.day)),
+        workspace.join(COMMON_DIR),
+    ]
+    .map(|dir| dir.canonicalize().unwrap_or(dir));
+    for dir in &dirs {
+        println!("Watching {} for changes", dir.display());
+    }
+
+    // None until the first build, so that one happens whatever the directories hold
+    let mut last_snapshot = None;
+    let mut last_answers: Option<Vec<PartRun>> = None;
+    loop {
+        let snapshot = snapshot_mtimes(&dirs);
+        if last_snapshot.as_ref() == Some(&snapshot) {
+            sleep(POLL_INTERVAL);
+            continue;
+        }
+        let first = last_snapshot.is_none();
+        last_snapshot = Some(snapshot);
+
+        println!(
+            "\n==== {} day {:02}: {}, rebuilding",
+            day.year,
+            day.day,
+            if first { "starting" } else { "change detected" }
+        );
+        if let Some(answers) = build_and_run(day, options) {
+            print_answers(&answers, last_answers.as_deref());
+            last_answers = Some(answers);
+        }
+    }
+}
+
+/// Modification time of every file below any of `dirs`.
+fn snapshot_mtimes(dirs: &[PathBuf]) -> HashMap<PathBuf, SystemTime> {
+    let mut mtimes = HashMap::new();
+    let mut pending = dirs.to_vec();
+
+    while let Some(dir) = pending.pop() {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+
+        for entry in entries.flatten() {
+            let Ok(metadata) = entry.metadata() else {
+                continue;
+            };
+
+            if metadata.is_dir() {
+                pending.push(entry.path());
+            } else if let Ok(modified) = metadata.modified() {
+                mtimes.insert(entry.path(), modified);
+            }
+        }
+    }
+
+    mtimes
+}
+
+fn cargo(options: &WatchOptions, subcommand: &str) -> Command {
+    let mut command = Command::new(std::env::var_os("CARGO").unwrap_or(OsString::from("cargo")));
+    command
+        .current_dir(WORKSPACE_DIR)
+        .args([subcommand, "--quiet", "-p", "aoc"]);
+    if options.release {
+        command.arg("--release");
+    }
+    command
+}
+
+/// One part as reported by `aoc run --format json`.
+struct PartRun {
+    part: i128,
+    /// The answer, or what went wrong.
+    answer: Result<String, String>,
+    elapsed: Option<Duration>,
+}
+
+impl PartRun {
+    fn from_record(record: &Json) -> Option<Self> {
+        let field = |key| record.get(key).and_then(Json::as_str).map(str::to_string);
+        let answer = match field("status")?.as_str() {
+            "ok" => Ok(field("answer")?),
+            _ => Err(field("error")?),
+        };
+
+        Some(Self {
+            part: record.get("part")?.as_int()?,
+            answer,
+            elapsed: record
+                .get("elapsed_ns")
+                .and_then(Json::as_int)
+                .map(|v| Duration::from_nanos(v as u64)),
+        })
+    }
+}
+
+/// Returns every part that ran, or `None` if building or running failed.
+fn build_and_run(day: &Day, options: &WatchOptions) -> Option<Vec<PartRun>> {
+    let build = match cargo(options, "build").output() {
+        Ok(v) => v,
+        Err(err) => {
+            println!("failed running cargo: {err}");
+            return None;
+        }
+    };
+
+    if !build.status.success() {
+        println!("build failed:");
+        print!("{}", String::from_utf8_lossy(&build.stderr));
+        return None;
+    }
+
+    let mut run = cargo(options, "run");
+    run.args(["--", "run", &day.year.to_string(), &day.day.to_string()])
+        .args(["--format", "json"]);
+    if options.example {
+        run.arg("--example");
+    }
+    if let Some(timeout) = options.timeout {
+        run.args(["--timeout", &timeout.as_secs_f64().to_string()]);
+    }
+
+    let output = match run.output() {
+        Ok(v) => v,
+        Err(err) => {
+            println!("failed running cargo: {err}");
+            return None;
+        }
+    };
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    if !output.status.success() {
+        println!("run failed:");
+        print!("{stdout}");
+        print!("{}", String::from_utf8_lossy(&output.stderr));
+        return None;
+    }
+
+    // One record per line, see records.rs
+    let mut parts = Vec::new();
+    for line in stdout.lines() {
+        let part = line
+            .parse::<Json>()
+            .ok()
+            .as_ref()
+            .and_then(PartRun::from_record);
+        let Some(part) = part else {
+            println!("unexpected output from aoc run: {line}");
+            return None;
+        };
+        parts.push(part);
+    }
+
+    Some(parts)
+}
+
+fn print_answers(parts: &[PartRun], previous: Option<&[PartRun]>) {
+    for part in parts {
+        let line = match (&part.answer, part.elapsed) {
+            (Ok(answer), Some(elapsed)) => format!("{answer} ({})", format_duration(elapsed)),
+            (Ok(answer), None) => answer.clone(),
+            (Err(err), _) => format!("failed: {err}"),
+        };
+        let previous_answer = previous
+            .and_then(|v| v.iter().find(|prev| prev.part == part.part))
+            .map(|prev| &prev.answer);
+
+        let label = format!("part {}", part.part);
+        match previous_answer {
+            Some(prev) if *prev != part.answer => {
+                let prev = match prev {
+                    Ok(answer) => answer.clone(),
+                    Err(err) => format!("failed: {err}"),
+                };
+                println!("{label}: {line}  (changed, was {prev})")
+            }
+            Some(_) => println!("{label}: {line}  (unchanged)"),
+            None => println!("{label}: {line}"),
+        }
+    }
+}
@@ -0,0 +1,178 @@
+//! The `aoc run --format json` records, the format is documented in `src/records.rs`.
+
+use std::process::Command;
+
+use aoc::{days::Day, records::part_records, runner::run_day};
+use common::json::Json;
+
+const KEYS: [&str; 12] = [
+    "year",
+    "day",
+    "part",
+    "input",
+    "status",
+    "answer",
+    "error",
+    "parse_ns",
+    "elapsed_ns",
+    "allocations",
+    "peak_rss_bytes",
+    "cpu_ns",
+];
+
+fn run_json(args: &[&str]) -> Vec<Json> {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .arg("run")
+        .args(args)
+        .args(["--format", "json", "-q"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(parse_record)
+        .collect()
+}
+
+/// Parses a record, checking it has every key in order.
+fn parse_record(line: &str) -> Json {
+    let record: Json = line.parse().unwrap();
+    let Json::Object(entries) = &record else {
+        panic!("record isn't an object: {line}");
+    };
+    assert_eq!(
+        entries.iter().map(|(k, _)| k.as_str()).collect::<Vec<_>>(),
+        KEYS
+    );
+    record
+}
+
+fn int(record: &Json, key: &str) -> Option<i128> {
+    record.get(key).and_then(Json::as_int)
+}
+
+fn string<'a>(record: &'a Json, key: &str) -> Option<&'a str> {
+    record.get(key).and_then(Json::as_str)
+}
+
+/// The records of running `parse` and the parts through the runner, filed under day 1.
+fn records_of(
+    parse: impl FnOnce(&str),
+    part_1: impl FnOnce(&()) -> Result<String, String>,
+    part_2: impl FnOnce(&()) -> Result<String, String>,
+) -> Vec<Json> {
+    let day = Day::find(2024, 1).unwrap();
+    let result = run_day("input", None, parse, part_1, part_2);
+    part_records(day, "real", &result)
+        .iter()
+        .map(|v| parse_record(&v.to_string()))
+        .collect()
+}
+
+#[test]
+fn one_record_per_part() {
+    let records = run_json(&["2024", "7", "--example"]);
+
+    assert_eq!(records.len(), 2);
+    for (record, answer) in records.iter().zip(["3749", "11387"]) {
+        assert_eq!(int(record, "year"), Some(2024));
+        assert_eq!(int(record, "day"), Some(7));
+        assert_eq!(string(record, "input"), Some("example"));
+        assert_eq!(string(record, "status"), Some("ok"));
+        assert_eq!(string(record, "answer"), Some(answer));
+        assert!(record.get("error").is_some_and(Json::is_null));
+        assert!(int(record, "parse_ns").is_some());
+        assert!(int(record, "elapsed_ns").is_some());
+    }
+    assert_eq!(int(&records[0], "part"), Some(1));
+    assert_eq!(int(&records[1], "part"), Some(2));
+}
+
+#[test]
+fn timed_out_parts() {
+    let records = run_json(&["2024", "16", "--example", "--timeout", "0"]);
+
+    assert_eq!(records.len(), 2);
+    for (i, record) in records.iter().enumerate() {
+        assert_eq!(int(record, "part"), Some(i as i128 + 1));
+        assert_eq!(string(record, "status"), Some("timeout"));
+        assert!(record.get("answer").is_some_and(Json::is_null));
+        assert!(string(record, "error").is_some_and(|v| v.starts_with("timed out after ")));
+    }
+}
+
+#[test]
+fn failed_parts() {
+    // The example robots never form a tree, which part 2 returns as an error
+    let records = run_json(&["2024", "14", "--example"]);
+
+    assert_eq!(string(&records[0], "status"), Some("ok"));
+    assert_eq!(string(&records[1], "status"), Some("error"));
+    assert!(records[1].get("answer").is_some_and(Json::is_null));
+    assert_eq!(
+        string(&records[1], "error"),
+        Some("no christmas tree within 77 steps")
+    );
+}
+
+#[test]
+fn panicking_and_unfinished_parts() {
+    let records = records_of(|_| (), |_| panic!("ran off the map"), |_| todo!());
+
+    assert_eq!(string(&records[0], "status"), Some("error"));
+    assert_eq!(string(&records[0], "error"), Some("ran off the map"));
+    assert_eq!(string(&records[1], "status"), Some("not_implemented"));
+    assert_eq!(string(&records[1], "error"), Some("not yet implemented"));
+    for record in &records {
+        assert!(record.get("answer").is_some_and(Json::is_null));
+        assert!(int(record, "elapsed_ns").is_some());
+    }
+}
+
+#[test]
+fn failed_parse() {
+    let records = records_of(
+        |_| panic!("bad line 3"),
+        |_| Ok("1".to_string()),
+        |_| Ok("2".to_string()),
+    );
+
+    assert_eq!(records.len(), 2);
+    for (i, record) in records.iter().enumerate() {
+        assert_eq!(int(record, "part"), Some(i as i128 + 1));
+        assert_eq!(string(record, "status"), Some("error"));
+        assert_eq!(string(record, "error"), Some("parse failed: bad line 3"));
+        assert!(int(record, "parse_ns").is_some());
+        for key in [
+            "answer",
+            "elapsed_ns",
+            "allocations",
+            "peak_rss_bytes",
+            "cpu_ns",
+        ] {
+            assert!(record.get(key).is_some_and(Json::is_null), "{key}");
+        }
+    }
+}
+
+#[test]
+fn one_record_per_part_for_every_day() {
+    let records = run_json(&["2024", "--example", "--timeout", "0"]);
+
+    // Every day that ran has part 1 followed by part 2, whichever days have examples
+    let mut days: Vec<(i128, Vec<i128>)> = Vec::new();
+    for record in &records {
+        let (day, part) = (int(record, "day").unwrap(), int(record, "part").unwrap());
+        match days.last_mut() {
+            Some((last, parts)) if *last == day => parts.push(part),
+            _ => days.push((day, vec![part])),
+        }
+    }
+
+    assert!(!days.is_empty());
+    for (day, parts) in &days {
+        assert_eq!(parts, &[1, 2], "day {day}");
+    }
+}
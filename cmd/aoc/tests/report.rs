@@ -0,0 +1,122 @@
+use std::path::{Path, PathBuf};
+
+use aoc::{
+    answers::Answers,
+    days::Day,
+    report::{Report, ReportOptions},
+    runner::{run_day, DayResult},
+};
+
+/// A fresh directory for one test, with visualisations for 2024 day 1 in `vis/2024`.
+fn test_dir(test: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-report-{}-{test}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(dir.join("vis/2024")).unwrap();
+    for name in ["day-01.gif", "day-01 (wide).svg", "day-010.gif"] {
+        std::fs::write(dir.join("vis/2024").join(name), "").unwrap();
+    }
+    dir
+}
+
+/// 2024 day 1 with the given answers, part 1 is known to be `42` and part 2 `7`.
+fn report(dir: &Path, answers: [Result<&str, &str>; 2], redact: bool) -> Report {
+    let day = Day::find(2024, 1).unwrap();
+    let [part_1, part_2] = answers.map(|v| v.map(str::to_string).map_err(str::to_string));
+    let result: DayResult = run_day("input", None, |_| (), |_| part_1, |_| part_2);
+    let answers = Answers::parse("2024 1 1 42\n2024 1 2 7\n").unwrap();
+
+    Report::new(
+        &[(day, result)],
+        &ReportOptions {
+            redact,
+            answers: Some(&answers),
+            vis_dir: &dir.join("vis"),
+            input_name: "real",
+        },
+    )
+}
+
+/// The table row of the only day in a markdown report.
+fn markdown_row(markdown: &str) -> String {
+    let rows = markdown
+        .lines()
+        .filter(|v| v.starts_with("| 2024 |"))
+        .collect::<Vec<_>>();
+    assert_eq!(rows.len(), 1, "{markdown}");
+    rows[0].to_string()
+}
+
+#[test]
+fn markdown_escapes_answers_and_keeps_them_on_one_row() {
+    let dir = test_dir("markdown-escapes");
+    let report = report(&dir, [Ok("42"), Ok("a|b\n*c*_d\\")], false);
+    let markdown = report.to_markdown(&dir.join("report.md"));
+
+    assert!(markdown.starts_with("# Advent of Code 2024\n\nUsing the real inputs, 1 of 2 stars"));
+    let row = markdown_row(&markdown);
+    assert!(row.contains(" | 42 | "), "{row}");
+    assert!(row.contains(r" | a\|b<br>\*c\*\_d\\ | "), "{row}");
+    assert!(row.contains(" | ★✗ | "), "{row}");
+}
+
+#[test]
+fn markdown_errors_and_redacted_answers() {
+    let dir = test_dir("markdown-errors");
+    let report = report(&dir, [Ok("42"), Err("no tree\nat <all>")], true);
+    let row = markdown_row(&report.to_markdown(&dir.join("report.md")));
+
+    assert!(row.contains(" | (redacted) | "), "{row}");
+    assert!(row.contains(r" | error: no tree at \<all\> | "), "{row}");
+    // The error had an answer to get, so it counts as wrong
+    assert!(row.contains(" | ★✗ | "), "{row}");
+}
+
+#[test]
+fn markdown_links_are_relative_to_the_report() {
+    let dir = test_dir("markdown-links");
+    let report = report(&dir, [Ok("42"), Ok("7")], false);
+
+    let row = markdown_row(&report.to_markdown(&dir.join("report.md")));
+    assert!(
+        row.ends_with(
+            " | [day-01 (wide).svg](vis/2024/day-01%20%28wide%29.svg), \
+             [day-01.gif](vis/2024/day-01.gif) |"
+        ),
+        "{row}"
+    );
+
+    let markdown = report.to_markdown(&dir.join("reports/./2024/report.md"));
+    assert!(
+        markdown_row(&markdown).ends_with("[day-01.gif](../../vis/2024/day-01.gif) |"),
+        "{markdown}"
+    );
+}
+
+#[test]
+fn html_escapes_answers_and_links_relative_to_the_page() {
+    let dir = test_dir("html");
+    let report = report(&dir, [Ok("<42>"), Err("bad & worse")], false);
+    let html = report.to_html(&dir.join("site/index.html"));
+
+    assert!(html.starts_with("<!DOCTYPE html>\n"));
+    assert!(html.contains("<title>Advent of Code 2024</title>"));
+    assert!(html.contains("<p>Using the real inputs, 0 of 2 stars in "));
+    assert!(html.contains("<td class=\"answer\">&lt;42&gt;</td>"));
+    assert!(html.contains("<td class=\"answer error\">error: bad &amp; worse</td>"));
+    assert!(html.contains("<td class=\"stars\">✗✗</td>"));
+    assert!(html.contains(
+        "<a href=\"../vis/2024/day-01%20%28wide%29.svg\">day-01 (wide).svg</a>, \
+         <a href=\"../vis/2024/day-01.gif\">day-01.gif</a></td></tr>"
+    ));
+    assert!(html.ends_with("</table>\n</body>\n</html>\n"));
+}
+
+#[test]
+fn html_keeps_line_breaks_in_answers() {
+    let dir = test_dir("html-lines");
+    let report = report(&dir, [Ok("#.\n.#"), Ok("7")], false);
+    let html = report.to_html(Path::new("index.html"));
+
+    assert!(html.contains("td.answer { font-family: monospace; white-space: pre; }"));
+    assert!(html.contains("<td class=\"answer\">#.\n.#</td>"));
+}
@@ -0,0 +1,29 @@
+//! Timing for the `benches/` of the day crates, which use `harness = false` since the built in
+//! bench harness needs nightly. Run them with `cargo bench -p <crate>`.
+
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+/// Runs `f` `runs` times after one warm up run, prints and returns the median time.
+pub fn bench<T>(label: &str, runs: usize, mut f: impl FnMut() -> T) -> Duration {
+    assert!(runs > 0, "benchmark needs at least one run");
+
+    black_box(f());
+    let mut times = (0..runs)
+        .map(|_| {
+            let started = Instant::now();
+            black_box(f());
+            started.elapsed()
+        })
+        .collect::<Vec<_>>();
+    times.sort();
+
+    let median = times[times.len() / 2];
+    println!(
+        "{label:<24} median {median:>12.2?}, min {:>12.2?} over {runs} runs",
+        times[0]
+    );
+    median
+}
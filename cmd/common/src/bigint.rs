@@ -0,0 +1,613 @@
+//! Integers that don't overflow, for the days whose numbers outgrow `u64` or `i128` on bigger
+//! inputs.
+//!
+//! Stored as base 2^32 digits, least significant first, always without leading zero digits so
+//! derived equality and hashing work. Multiplication is schoolbook and division shifts and
+//! subtracts a bit at a time, fast enough for numbers a few hundred digits long.
+
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
+    str::FromStr,
+};
+
+/// Largest power of 10 fitting a digit, used to parse and print 9 decimal digits at a time.
+const DECIMAL_CHUNK: u32 = 1_000_000_000;
+
+/// A non-negative integer of any size.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    digits: Vec<u32>,
+}
+
+impl BigUint {
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    pub fn one() -> Self {
+        Self { digits: vec![1] }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    /// Number of bits needed to write the number, 0 for 0.
+    pub fn bits(&self) -> u64 {
+        match self.digits.last() {
+            Some(top) => self.digits.len() as u64 * 32 - u64::from(top.leading_zeros()),
+            None => 0,
+        }
+    }
+
+    pub fn to_u64(&self) -> Option<u64> {
+        self.to_u128()?.try_into().ok()
+    }
+
+    pub fn to_u128(&self) -> Option<u128> {
+        if self.digits.len() > 4 {
+            return None;
+        }
+        Some(
+            self.digits
+                .iter()
+                .rev()
+                .fold(0, |acc, d| (acc << 32) | u128::from(*d)),
+        )
+    }
+
+    /// `self - other`, `None` if that would be negative.
+    pub fn checked_sub(&self, other: &BigUint) -> Option<BigUint> {
+        (*self >= *other).then(|| Self::from_digits(sub_digits(&self.digits, &other.digits)))
+    }
+
+    /// Quotient and remainder, panics when dividing by zero.
+    pub fn div_rem(&self, divisor: &BigUint) -> (BigUint, BigUint) {
+        assert!(!divisor.is_zero(), "attempt to divide by zero");
+        if self < divisor {
+            return (BigUint::zero(), self.clone());
+        }
+        if let [small] = divisor.digits[..] {
+            let (quotient, remainder) = self.div_rem_small(small);
+            return (quotient, BigUint::from(remainder));
+        }
+
+        let mut quotient = vec![0; self.digits.len()];
+        let mut remainder = BigUint::zero();
+        for bit in (0..self.bits()).rev() {
+            remainder.shift_left_one();
+            if self.bit(bit) {
+                remainder.set_lowest_bit();
+            }
+            if remainder >= *divisor {
+                remainder = Self::from_digits(sub_digits(&remainder.digits, &divisor.digits));
+                quotient[(bit / 32) as usize] |= 1 << (bit % 32);
+            }
+        }
+        (Self::from_digits(quotient), remainder)
+    }
+
+    pub fn pow(&self, mut exp: u32) -> BigUint {
+        let mut base = self.clone();
+        let mut result = BigUint::one();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = Self::from_digits(mul_digits(&result.digits, &base.digits));
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = Self::from_digits(mul_digits(&base.digits, &base.digits));
+            }
+        }
+        result
+    }
+
+    fn from_digits(mut digits: Vec<u32>) -> Self {
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        Self { digits }
+    }
+
+    fn div_rem_small(&self, divisor: u32) -> (BigUint, u32) {
+        let mut quotient = vec![0; self.digits.len()];
+        let mut remainder = 0u64;
+        for (i, digit) in self.digits.iter().enumerate().rev() {
+            let current = (remainder << 32) | u64::from(*digit);
+            quotient[i] = (current / u64::from(divisor)) as u32;
+            remainder = current % u64::from(divisor);
+        }
+        (Self::from_digits(quotient), remainder as u32)
+    }
+
+    /// `self * factor + addend`, in place.
+    fn mul_add_small(&mut self, factor: u32, addend: u32) {
+        let mut carry = u64::from(addend);
+        for digit in &mut self.digits {
+            let current = u64::from(*digit) * u64::from(factor) + carry;
+            *digit = current as u32;
+            carry = current >> 32;
+        }
+        if carry > 0 {
+            self.digits.push(carry as u32);
+        }
+        *self = Self::from_digits(std::mem::take(&mut self.digits));
+    }
+
+    fn bit(&self, bit: u64) -> bool {
+        self.digits[(bit / 32) as usize] >> (bit % 32) & 1 == 1
+    }
+
+    fn shift_left_one(&mut self) {
+        let mut carry = 0;
+        for digit in &mut self.digits {
+            let next_carry = *digit >> 31;
+            *digit = (*digit << 1) | carry;
+            carry = next_carry;
+        }
+        if carry > 0 {
+            self.digits.push(carry);
+        }
+    }
+
+    fn set_lowest_bit(&mut self) {
+        match self.digits.first_mut() {
+            Some(digit) => *digit |= 1,
+            None => self.digits.push(1),
+        }
+    }
+}
+
+fn add_digits(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut out = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0u64;
+    for (i, digit) in long.iter().enumerate() {
+        let current = u64::from(*digit) + u64::from(short.get(i).copied().unwrap_or(0)) + carry;
+        out.push(current as u32);
+        carry = current >> 32;
+    }
+    if carry > 0 {
+        out.push(carry as u32);
+    }
+    out
+}
+
+/// `a - b` where `a` is at least `b`.
+fn sub_digits(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut out = Vec::with_capacity(a.len());
+    let mut borrow = 0i64;
+    for (i, digit) in a.iter().enumerate() {
+        let mut current = i64::from(*digit) - i64::from(b.get(i).copied().unwrap_or(0)) - borrow;
+        borrow = 0;
+        if current < 0 {
+            current += 1 << 32;
+            borrow = 1;
+        }
+        out.push(current as u32);
+    }
+    debug_assert!(borrow == 0, "subtracted a larger number");
+    out
+}
+
+fn mul_digits(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut out = vec![0u32; a.len() + b.len()];
+    for (i, x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, y) in b.iter().enumerate() {
+            let current = u64::from(out[i + j]) + u64::from(*x) * u64::from(*y) + carry;
+            out[i + j] = current as u32;
+            carry = current >> 32;
+        }
+        out[i + b.len()] = carry as u32;
+    }
+    out
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.digits
+            .len()
+            .cmp(&other.digits.len())
+            .then_with(|| self.digits.iter().rev().cmp(other.digits.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl From<u128> for BigUint {
+    fn from(mut value: u128) -> Self {
+        let mut digits = Vec::new();
+        while value > 0 {
+            digits.push(value as u32);
+            value >>= 32;
+        }
+        Self { digits }
+    }
+}
+
+macro_rules! biguint_from {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for BigUint {
+                fn from(value: $t) -> Self {
+                    BigUint::from(value as u128)
+                }
+            }
+        )*
+    };
+}
+
+biguint_from!(u8, u16, u32, u64, usize);
+
+/// A string that isn't an optional sign followed by decimal digits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseBigIntError;
+
+impl Display for ParseBigIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid digit found in string")
+    }
+}
+
+impl std::error::Error for ParseBigIntError {}
+
+impl FromStr for BigUint {
+    type Err = ParseBigIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = s.strip_prefix('+').unwrap_or(s);
+        if digits.is_empty() || !digits.bytes().all(|v| v.is_ascii_digit()) {
+            return Err(ParseBigIntError);
+        }
+
+        let mut value = BigUint::zero();
+        for chunk in digits.as_bytes().chunks(9) {
+            let chunk_value = chunk
+                .iter()
+                .fold(0, |acc, v| acc * 10 + u32::from(v - b'0'));
+            value.mul_add_small(10u32.pow(chunk.len() as u32), chunk_value);
+        }
+        Ok(value)
+    }
+}
+
+impl Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad_integral(true, "", &decimal(self))
+    }
+}
+
+fn decimal(value: &BigUint) -> String {
+    let mut chunks = Vec::new();
+    let mut rest = value.clone();
+    while !rest.is_zero() {
+        let (quotient, chunk) = rest.div_rem_small(DECIMAL_CHUNK);
+        chunks.push(chunk);
+        rest = quotient;
+    }
+
+    let mut out = chunks.pop().unwrap_or(0).to_string();
+    for chunk in chunks.iter().rev() {
+        out.push_str(&format!("{chunk:09}"));
+    }
+    out
+}
+
+/// Implements the owned and assigning forms of an operator from the `&a op &b` one.
+macro_rules! forward_ops {
+    ($t:ty, $($op:ident $method:ident $assign_op:ident $assign_method:ident),*) => {
+        $(
+            impl $op<$t> for $t {
+                type Output = $t;
+
+                fn $method(self, rhs: $t) -> $t {
+                    (&self).$method(&rhs)
+                }
+            }
+
+            impl $op<&$t> for $t {
+                type Output = $t;
+
+                fn $method(self, rhs: &$t) -> $t {
+                    (&self).$method(rhs)
+                }
+            }
+
+            impl $op<$t> for &$t {
+                type Output = $t;
+
+                fn $method(self, rhs: $t) -> $t {
+                    self.$method(&rhs)
+                }
+            }
+
+            impl $assign_op<$t> for $t {
+                fn $assign_method(&mut self, rhs: $t) {
+                    *self = (&*self).$method(&rhs);
+                }
+            }
+
+            impl $assign_op<&$t> for $t {
+                fn $assign_method(&mut self, rhs: &$t) {
+                    *self = (&*self).$method(rhs);
+                }
+            }
+        )*
+    };
+}
+
+impl Add<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, rhs: &BigUint) -> BigUint {
+        BigUint::from_digits(add_digits(&self.digits, &rhs.digits))
+    }
+}
+
+impl Sub<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    /// Panics if the result would be negative, like the primitive unsigned types in debug.
+    fn sub(self, rhs: &BigUint) -> BigUint {
+        self.checked_sub(rhs)
+            .expect("attempt to subtract with overflow")
+    }
+}
+
+impl Mul<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, rhs: &BigUint) -> BigUint {
+        BigUint::from_digits(mul_digits(&self.digits, &rhs.digits))
+    }
+}
+
+impl Div<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn div(self, rhs: &BigUint) -> BigUint {
+        self.div_rem(rhs).0
+    }
+}
+
+impl Rem<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn rem(self, rhs: &BigUint) -> BigUint {
+        self.div_rem(rhs).1
+    }
+}
+
+forward_ops!(
+    BigUint,
+    Add add AddAssign add_assign,
+    Sub sub SubAssign sub_assign,
+    Mul mul MulAssign mul_assign,
+    Div div DivAssign div_assign,
+    Rem rem RemAssign rem_assign
+);
+
+/// A signed integer of any size.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigInt {
+    /// Never set for zero, so there's only one zero.
+    negative: bool,
+    magnitude: BigUint,
+}
+
+impl BigInt {
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    pub fn one() -> Self {
+        BigUint::one().into()
+    }
+
+    pub fn from_parts(negative: bool, magnitude: BigUint) -> Self {
+        Self {
+            negative: negative && !magnitude.is_zero(),
+            magnitude,
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_zero()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// The absolute value.
+    pub fn magnitude(&self) -> &BigUint {
+        &self.magnitude
+    }
+
+    pub fn abs(&self) -> BigInt {
+        Self::from_parts(false, self.magnitude.clone())
+    }
+
+    pub fn to_i64(&self) -> Option<i64> {
+        self.to_i128()?.try_into().ok()
+    }
+
+    pub fn to_i128(&self) -> Option<i128> {
+        let magnitude = self.magnitude.to_u128()?;
+        if self.negative {
+            0i128.checked_sub_unsigned(magnitude)
+        } else {
+            magnitude.try_into().ok()
+        }
+    }
+
+    /// Quotient rounded towards zero and the remainder with the sign of `self`, like the
+    /// primitive types do. Panics when dividing by zero.
+    pub fn div_rem(&self, divisor: &BigInt) -> (BigInt, BigInt) {
+        let (quotient, remainder) = self.magnitude.div_rem(&divisor.magnitude);
+        (
+            Self::from_parts(self.negative != divisor.negative, quotient),
+            Self::from_parts(self.negative, remainder),
+        )
+    }
+
+    pub fn pow(&self, exp: u32) -> BigInt {
+        Self::from_parts(self.negative && exp % 2 == 1, self.magnitude.pow(exp))
+    }
+
+    /// `self` plus a number with the given sign and magnitude.
+    fn add_signed(&self, negative: bool, magnitude: &BigUint) -> BigInt {
+        if self.negative == negative {
+            return Self::from_parts(negative, &self.magnitude + magnitude);
+        }
+        match self.magnitude.cmp(magnitude) {
+            Ordering::Less => Self::from_parts(
+                negative,
+                BigUint::from_digits(sub_digits(&magnitude.digits, &self.magnitude.digits)),
+            ),
+            _ => Self::from_parts(
+                self.negative,
+                BigUint::from_digits(sub_digits(&self.magnitude.digits, &magnitude.digits)),
+            ),
+        }
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl From<BigUint> for BigInt {
+    fn from(magnitude: BigUint) -> Self {
+        Self::from_parts(false, magnitude)
+    }
+}
+
+impl From<i128> for BigInt {
+    fn from(value: i128) -> Self {
+        Self::from_parts(value < 0, BigUint::from(value.unsigned_abs()))
+    }
+}
+
+macro_rules! bigint_from {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for BigInt {
+                fn from(value: $t) -> Self {
+                    BigInt::from(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+bigint_from!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl FromStr for BigInt {
+    type Err = ParseBigIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix('-') {
+            Some(rest) if !rest.starts_with('+') => Ok(Self::from_parts(true, rest.parse()?)),
+            Some(_) => Err(ParseBigIntError),
+            None => Ok(Self::from_parts(false, s.parse()?)),
+        }
+    }
+}
+
+impl Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad_integral(!self.negative, "", &decimal(&self.magnitude))
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::from_parts(!self.negative, self.magnitude.clone())
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::from_parts(!self.negative, self.magnitude)
+    }
+}
+
+impl Add<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, rhs: &BigInt) -> BigInt {
+        self.add_signed(rhs.negative, &rhs.magnitude)
+    }
+}
+
+impl Sub<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, rhs: &BigInt) -> BigInt {
+        self.add_signed(!rhs.negative, &rhs.magnitude)
+    }
+}
+
+impl Mul<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, rhs: &BigInt) -> BigInt {
+        BigInt::from_parts(
+            self.negative != rhs.negative,
+            &self.magnitude * &rhs.magnitude,
+        )
+    }
+}
+
+impl Div<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn div(self, rhs: &BigInt) -> BigInt {
+        self.div_rem(rhs).0
+    }
+}
+
+impl Rem<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn rem(self, rhs: &BigInt) -> BigInt {
+        self.div_rem(rhs).1
+    }
+}
+
+forward_ops!(
+    BigInt,
+    Add add AddAssign add_assign,
+    Sub sub SubAssign sub_assign,
+    Mul mul MulAssign mul_assign,
+    Div div DivAssign div_assign,
+    Rem rem RemAssign rem_assign
+);
@@ -0,0 +1,225 @@
+//! Fixed size sets of bits, for tracking visited indices and grid cells without hashing.
+
+/// Set of the indices `0..capacity`, one bit each.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitSet {
+    words: Vec<u64>,
+    capacity: usize,
+}
+
+impl BitSet {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            words: vec![0; capacity.div_ceil(64)],
+            capacity,
+        }
+    }
+
+    /// Number of indices the set can hold, see [`BitSet::count`] for the number in it.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Whether no index is in the set.
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|v| *v == 0)
+    }
+
+    /// Adds `index`, returns whether it wasn't in the set yet.
+    pub fn insert(&mut self, index: usize) -> bool {
+        let (word, mask) = self.locate(index);
+        let was_set = self.words[word] & mask != 0;
+        self.words[word] |= mask;
+        !was_set
+    }
+
+    /// Removes `index`, returns whether it was in the set.
+    pub fn remove(&mut self, index: usize) -> bool {
+        let (word, mask) = self.locate(index);
+        let was_set = self.words[word] & mask != 0;
+        self.words[word] &= !mask;
+        was_set
+    }
+
+    pub fn contains(&self, index: usize) -> bool {
+        let (word, mask) = self.locate(index);
+        self.words[word] & mask != 0
+    }
+
+    /// Removes every index while keeping the allocation.
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    /// Number of indices in the set.
+    pub fn count(&self) -> usize {
+        self.words.iter().map(|v| v.count_ones() as usize).sum()
+    }
+
+    /// Adds every index of `other`, which has to be of the same capacity.
+    pub fn union_with(&mut self, other: &BitSet) {
+        assert_eq!(self.capacity, other.capacity, "bit sets differ in capacity");
+        for (a, b) in self.words.iter_mut().zip(&other.words) {
+            *a |= b;
+        }
+    }
+
+    /// Keeps only the indices also in `other`, which has to be of the same capacity.
+    pub fn intersect_with(&mut self, other: &BitSet) {
+        assert_eq!(self.capacity, other.capacity, "bit sets differ in capacity");
+        for (a, b) in self.words.iter_mut().zip(&other.words) {
+            *a &= b;
+        }
+    }
+
+    /// The indices in the set in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, word)| {
+            let mut word = *word;
+            std::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+                let bit = word.trailing_zeros() as usize;
+                word &= word - 1;
+                Some(i * 64 + bit)
+            })
+        })
+    }
+
+    fn locate(&self, index: usize) -> (usize, u64) {
+        assert!(
+            index < self.capacity,
+            "index {index} out of range for bit set of capacity {}",
+            self.capacity
+        );
+        (index / 64, 1 << (index % 64))
+    }
+}
+
+/// A bit per cell of a `width` by `height` grid, or several when it has layers.
+///
+/// Layers hold a bit per cell and e.g direction, so a cell can be marked as entered facing up
+/// separately from entered facing left. The methods without a layer use the first one.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    width: usize,
+    height: usize,
+    layers: usize,
+    bits: BitSet,
+}
+
+impl BitGrid {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self::with_layers(width, height, 1)
+    }
+
+    pub fn with_layers(width: usize, height: usize, layers: usize) -> Self {
+        assert!(layers > 0, "bit grid needs at least one layer");
+        Self {
+            width,
+            height,
+            layers,
+            bits: BitSet::new(width * height * layers),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn layers(&self) -> usize {
+        self.layers
+    }
+
+    /// Whether signed coordinates are on the grid, handy before converting them.
+    pub fn in_bounds(&self, x: i64, y: i64) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    pub fn insert(&mut self, x: usize, y: usize) -> bool {
+        self.insert_layer(x, y, 0)
+    }
+
+    pub fn remove(&mut self, x: usize, y: usize) -> bool {
+        self.remove_layer(x, y, 0)
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        self.contains_layer(x, y, 0)
+    }
+
+    /// Sets the bit of the cell in `layer`, returns whether it wasn't set yet.
+    pub fn insert_layer(&mut self, x: usize, y: usize, layer: usize) -> bool {
+        let index = self.index(x, y, layer);
+        self.bits.insert(index)
+    }
+
+    pub fn remove_layer(&mut self, x: usize, y: usize, layer: usize) -> bool {
+        let index = self.index(x, y, layer);
+        self.bits.remove(index)
+    }
+
+    pub fn contains_layer(&self, x: usize, y: usize, layer: usize) -> bool {
+        self.bits.contains(self.index(x, y, layer))
+    }
+
+    /// Whether the cell is set in any layer.
+    pub fn contains_any(&self, x: usize, y: usize) -> bool {
+        (0..self.layers).any(|layer| self.contains_layer(x, y, layer))
+    }
+
+    /// Number of cells set in any layer.
+    pub fn count(&self) -> usize {
+        if self.layers == 1 {
+            return self.bits.count();
+        }
+        self.cells().count()
+    }
+
+    /// Cells set in any layer, row by row.
+    pub fn cells(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..self.height)
+            .flat_map(move |y| (0..self.width).map(move |x| (x, y)))
+            .filter(|&(x, y)| self.contains_any(x, y))
+    }
+
+    /// Clears every cell in every layer while keeping the allocation.
+    pub fn clear(&mut self) {
+        self.bits.clear();
+    }
+
+    /// Sets every bit set in `other`, which has to be of the same size.
+    pub fn union_with(&mut self, other: &BitGrid) {
+        self.assert_same_shape(other);
+        self.bits.union_with(&other.bits);
+    }
+
+    /// Keeps only the bits also set in `other`, which has to be of the same size.
+    pub fn intersect_with(&mut self, other: &BitGrid) {
+        self.assert_same_shape(other);
+        self.bits.intersect_with(&other.bits);
+    }
+
+    fn assert_same_shape(&self, other: &BitGrid) {
+        assert!(
+            (self.width, self.height, self.layers) == (other.width, other.height, other.layers),
+            "bit grids differ in size"
+        );
+    }
+
+    fn index(&self, x: usize, y: usize, layer: usize) -> usize {
+        assert!(
+            x < self.width && y < self.height && layer < self.layers,
+            "cell {x}, {y} layer {layer} out of range for {}x{} bit grid with {} layers",
+            self.width,
+            self.height,
+            self.layers
+        );
+        (y * self.width + x) * self.layers + layer
+    }
+}
@@ -0,0 +1,71 @@
+//! Cooperative cancellation of long running solvers.
+//!
+//! A runner installs a [`CancelToken`] with [`with_token`] and cancels it once the time budget is
+//! used up, long loops call [`checkpoint`] to bail out by unwinding with a [`Cancelled`] payload.
+//! Without an installed token [`checkpoint`] never does anything, so solvers still run fine on
+//! their own.
+
+use std::{
+    cell::RefCell,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+thread_local! {
+    static CURRENT: RefCell<Option<CancelToken>> = const { RefCell::new(None) };
+}
+
+/// Shared flag that tells the solver running under it to stop, cheap to clone.
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+/// Unwind payload of a [`checkpoint`] that found its token cancelled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+/// Runs `f` with `token` as the current token of this thread.
+pub fn with_token<T>(token: &CancelToken, f: impl FnOnce() -> T) -> T {
+    // Restores the previous token even when `f` unwinds
+    struct Restore(Option<CancelToken>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            CURRENT.with(|v| *v.borrow_mut() = self.0.take());
+        }
+    }
+
+    let _restore = Restore(CURRENT.with(|v| v.borrow_mut().replace(token.clone())));
+    f()
+}
+
+/// Whether the current token, if any, got cancelled.
+pub fn is_cancelled() -> bool {
+    CURRENT.with(|v| v.borrow().as_ref().is_some_and(|v| v.is_cancelled()))
+}
+
+/// Unwinds with [`Cancelled`] if the current token got cancelled.
+///
+/// Unwinding skips the panic hook, so nothing gets printed.
+pub fn checkpoint() {
+    if is_cancelled() {
+        std::panic::resume_unwind(Box::new(Cancelled));
+    }
+}
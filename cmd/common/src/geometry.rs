@@ -0,0 +1,186 @@
+//! Exact 2D geometry on integer points, intersections come out as [`Rational`] coordinates so
+//! nothing gets rounded.
+//!
+//! Orientations are named for the y axis pointing up. On a grid with y growing downwards, like
+//! most puzzle inputs, clockwise and counter clockwise swap.
+
+use crate::{rational::Rational, IVec2};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    Clockwise,
+    CounterClockwise,
+    Collinear,
+}
+
+/// Cross product of `a - origin` and `b - origin`, positive when `origin`, `a`, `b` turn counter
+/// clockwise.
+pub fn cross(origin: IVec2, a: IVec2, b: IVec2) -> i128 {
+    let (ax, ay) = ((a.x - origin.x) as i128, (a.y - origin.y) as i128);
+    let (bx, by) = ((b.x - origin.x) as i128, (b.y - origin.y) as i128);
+    ax * by - ay * bx
+}
+
+/// Which way the path `a`, `b`, `c` turns at `b`.
+pub fn orientation(a: IVec2, b: IVec2, c: IVec2) -> Orientation {
+    match cross(a, b, c).signum() {
+        1 => Orientation::CounterClockwise,
+        -1 => Orientation::Clockwise,
+        _ => Orientation::Collinear,
+    }
+}
+
+/// A point with exact fractional coordinates.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct RVec2 {
+    pub x: Rational,
+    pub y: Rational,
+}
+
+impl RVec2 {
+    /// The point if both coordinates are whole numbers that fit an `i64`.
+    pub fn to_ivec2(&self) -> Option<IVec2> {
+        Some(IVec2 {
+            x: self.x.to_integer()?.try_into().ok()?,
+            y: self.y.to_integer()?.try_into().ok()?,
+        })
+    }
+}
+
+impl From<IVec2> for RVec2 {
+    fn from(v: IVec2) -> Self {
+        Self {
+            x: v.x.into(),
+            y: v.y.into(),
+        }
+    }
+}
+
+/// The infinite line through two different points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Line {
+    pub a: IVec2,
+    pub b: IVec2,
+}
+
+impl Line {
+    pub fn new(a: IVec2, b: IVec2) -> Self {
+        assert!(a != b, "line through a single point {a:?}");
+        Self { a, b }
+    }
+
+    /// The line through `point` going in `direction`.
+    pub fn through(point: IVec2, direction: IVec2) -> Self {
+        Self::new(
+            point,
+            IVec2 {
+                x: point.x + direction.x,
+                y: point.y + direction.y,
+            },
+        )
+    }
+
+    pub fn contains(&self, point: IVec2) -> bool {
+        orientation(self.a, self.b, point) == Orientation::Collinear
+    }
+
+    pub fn is_parallel(&self, other: &Line) -> bool {
+        direction_cross(self.a, self.b, other.a, other.b) == 0
+    }
+
+    /// The point the lines cross at, `None` for parallel lines including the line itself.
+    pub fn intersection(&self, other: &Line) -> Option<RVec2> {
+        let (t, _) = intersection_params(self.a, self.b, other.a, other.b)?;
+        Some(point_at(self.a, self.b, t))
+    }
+}
+
+/// The segment between two points, both ends included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Segment {
+    pub a: IVec2,
+    pub b: IVec2,
+}
+
+impl Segment {
+    pub fn new(a: IVec2, b: IVec2) -> Self {
+        Self { a, b }
+    }
+
+    pub fn contains(&self, point: IVec2) -> bool {
+        orientation(self.a, self.b, point) == Orientation::Collinear && self.bounds_contain(point)
+    }
+
+    /// Whether the segments share any point, including touching ends and collinear overlaps.
+    pub fn intersects(&self, other: &Segment) -> bool {
+        let o1 = orientation(self.a, self.b, other.a);
+        let o2 = orientation(self.a, self.b, other.b);
+        let o3 = orientation(other.a, other.b, self.a);
+        let o4 = orientation(other.a, other.b, self.b);
+
+        if o1 != o2 && o3 != o4 {
+            return true;
+        }
+
+        self.contains(other.a)
+            || self.contains(other.b)
+            || other.contains(self.a)
+            || other.contains(self.b)
+    }
+
+    /// The single point the segments cross at, `None` if they don't or overlap along a stretch.
+    pub fn intersection(&self, other: &Segment) -> Option<RVec2> {
+        let Some((t, u)) = intersection_params(self.a, self.b, other.a, other.b) else {
+            // Parallel, collinear segments can still touch at just an end
+            if !self.intersects(other) {
+                return None;
+            }
+            let shared = [other.a, other.b]
+                .into_iter()
+                .filter(|v| self.contains(*v))
+                .chain([self.a, self.b].into_iter().filter(|v| other.contains(*v)))
+                .collect::<Vec<_>>();
+            return shared
+                .iter()
+                .all(|v| *v == shared[0])
+                .then(|| shared[0].into());
+        };
+
+        let unit = Rational::ZERO..=Rational::ONE;
+        (unit.contains(&t) && unit.contains(&u)).then(|| point_at(self.a, self.b, t))
+    }
+
+    fn bounds_contain(&self, point: IVec2) -> bool {
+        (self.a.x.min(self.b.x)..=self.a.x.max(self.b.x)).contains(&point.x)
+            && (self.a.y.min(self.b.y)..=self.a.y.max(self.b.y)).contains(&point.y)
+    }
+}
+
+/// Cross product of the directions `b - a` and `d - c`.
+fn direction_cross(a: IVec2, b: IVec2, c: IVec2, d: IVec2) -> i128 {
+    let (rx, ry) = ((b.x - a.x) as i128, (b.y - a.y) as i128);
+    let (sx, sy) = ((d.x - c.x) as i128, (d.y - c.y) as i128);
+    rx * sy - ry * sx
+}
+
+/// The `t` and `u` where `a + t * (b - a) == c + u * (d - c)`, `None` when the directions are
+/// parallel.
+fn intersection_params(a: IVec2, b: IVec2, c: IVec2, d: IVec2) -> Option<(Rational, Rational)> {
+    let denom = direction_cross(a, b, c, d);
+    if denom == 0 {
+        return None;
+    }
+
+    // Crossing both sides of the equation with one of the directions cancels that term
+    let t = direction_cross(a, c, c, d);
+    let u = direction_cross(a, c, a, b);
+    Some((Rational::new(t, denom), Rational::new(u, denom)))
+}
+
+/// `a + t * (b - a)`.
+fn point_at(a: IVec2, b: IVec2, t: Rational) -> RVec2 {
+    RVec2 {
+        x: Rational::from(a.x) + t * Rational::from(b.x - a.x),
+        y: Rational::from(a.y) + t * Rational::from(b.y - a.y),
+    }
+}
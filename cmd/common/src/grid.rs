@@ -0,0 +1,226 @@
+//! Dense 2D grids stored row by row, with the rotations and flips puzzles like to match
+//! patterns under.
+
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+use crate::IVec2;
+
+/// A `width` by `height` grid with `(0, 0)` top left, x going right and y going down.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Grid with `f(x, y)` in every cell.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| f(x, y))
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Grid of the given rows, panics if they differ in length.
+    pub fn from_rows<R: IntoIterator<Item = T>>(rows: impl IntoIterator<Item = R>) -> Self {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for row in rows {
+            let before = cells.len();
+            cells.extend(row);
+            let row_width = cells.len() - before;
+            assert!(
+                *width.get_or_insert(row_width) == row_width,
+                "row {height} is {row_width} wide instead of {}",
+                width.unwrap()
+            );
+            height += 1;
+        }
+
+        Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        }
+    }
+
+    /// Grid of the lines of `input` with `f` applied to every character.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> T) -> Self {
+        Self::from_rows(
+            input
+                .lines()
+                .map(|line| line.chars().map(&mut f).collect::<Vec<_>>()),
+        )
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether signed coordinates are on the grid, handy before converting them.
+    pub fn in_bounds(&self, x: i64, y: i64) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        (x < self.width && y < self.height).then(|| &self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        (x < self.width && y < self.height).then(|| &mut self.cells[y * self.width + x])
+    }
+
+    /// The cell at `pos`, `None` off the grid on any side.
+    pub fn get_at(&self, pos: IVec2) -> Option<&T> {
+        self.in_bounds(pos.x, pos.y)
+            .then(|| &self.cells[pos.y as usize * self.width + pos.x as usize])
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        // chunks_exact panics on 0, an empty grid just has no rows
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    /// Every cell with its `(x, y)`, row by row.
+    pub fn cells(&self) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, v)| ((i % self.width, i / self.width), v))
+    }
+
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(&mut f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Mirrors along the diagonal from the top left, rows become columns.
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.height, self.width, |x, y| self[(y, x)].clone())
+    }
+
+    /// Rotates a quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Self {
+        Self::from_fn(self.height, self.width, |x, y| {
+            self[(y, self.height - 1 - x)].clone()
+        })
+    }
+
+    /// Rotates a half turn.
+    pub fn rotate_180(&self) -> Self {
+        let mut cells = self.cells.clone();
+        cells.reverse();
+        Self {
+            width: self.width,
+            height: self.height,
+            cells,
+        }
+    }
+
+    /// Rotates a quarter turn counter clockwise, the same as three turns clockwise.
+    pub fn rotate_ccw(&self) -> Self {
+        Self::from_fn(self.height, self.width, |x, y| {
+            self[(self.width - 1 - y, x)].clone()
+        })
+    }
+
+    /// Mirrors left to right, reversing every row.
+    pub fn flip_horizontal(&self) -> Self {
+        Self::from_fn(self.width, self.height, |x, y| {
+            self[(self.width - 1 - x, y)].clone()
+        })
+    }
+
+    /// Mirrors top to bottom, reversing the order of the rows.
+    pub fn flip_vertical(&self) -> Self {
+        Self::from_fn(self.width, self.height, |x, y| {
+            self[(x, self.height - 1 - y)].clone()
+        })
+    }
+
+    /// The grid under all 8 rotations and reflections, each distinct variant once, starting with
+    /// the grid itself.
+    pub fn symmetries(&self) -> impl Iterator<Item = Grid<T>>
+    where
+        T: PartialEq,
+    {
+        let mut variants: Vec<Grid<T>> = Vec::with_capacity(8);
+        let mut rotated = self.clone();
+        for _ in 0..4 {
+            let flipped = rotated.flip_horizontal();
+            for variant in [rotated.clone(), flipped] {
+                if !variants.contains(&variant) {
+                    variants.push(variant);
+                }
+            }
+            rotated = rotated.rotate_cw();
+        }
+        variants.into_iter()
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    /// The cell at `(x, y)`, panics off the grid.
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        self.get(x, y).unwrap_or_else(|| {
+            panic!(
+                "cell {x}, {y} out of range for {}x{} grid",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("cell {x}, {y} out of range for {width}x{height} grid"))
+    }
+}
+
+impl Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            let line = row.iter().collect::<String>();
+            writeln!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,181 @@
+//! Parsing character maps into a [`Grid`] while picking out the special cells, like where the
+//! guard or the start is, and complaining with coordinates when the map isn't what the day
+//! expects.
+
+use std::fmt::{self, Display};
+
+use crate::{grid::Grid, IVec2};
+
+/// How many times a marker has to show up in the map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Count {
+    ExactlyOne,
+    AtLeastOne,
+    Any,
+}
+
+impl Count {
+    fn allows(self, found: usize) -> bool {
+        match self {
+            Count::ExactlyOne => found == 1,
+            Count::AtLeastOne => found >= 1,
+            Count::Any => true,
+        }
+    }
+}
+
+impl Display for Count {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Count::ExactlyOne => "exactly one",
+            Count::AtLeastOne => "at least one",
+            Count::Any => "any number",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The mapping had no cell for this character.
+    UnknownChar { c: char, x: usize, y: usize },
+    /// A row had a different width than the first one.
+    RaggedRow {
+        y: usize,
+        width: usize,
+        expected: usize,
+    },
+    /// A marker showed up a number of times its [`Count`] doesn't allow.
+    MarkerCount {
+        marker: char,
+        expected: Count,
+        found: usize,
+    },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::UnknownChar { c, x, y } => write!(f, "unknown char {c:?} at {x}, {y}"),
+            ParseError::RaggedRow { y, width, expected } => {
+                write!(f, "row {y} is {width} wide instead of {expected}")
+            }
+            ParseError::MarkerCount {
+                marker,
+                expected,
+                found,
+            } => write!(f, "expected {expected} {marker:?} but found {found}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The parsed grid along with where every marker was found.
+#[derive(Debug, Clone)]
+pub struct ParsedGrid<T> {
+    pub grid: Grid<T>,
+    markers: Vec<(char, Vec<IVec2>)>,
+}
+
+impl<T> ParsedGrid<T> {
+    /// Positions of the marker row by row, panics if it wasn't declared.
+    pub fn all(&self, marker: char) -> &[IVec2] {
+        self.markers
+            .iter()
+            .find(|(c, _)| *c == marker)
+            .map(|(_, positions)| positions.as_slice())
+            .unwrap_or_else(|| panic!("{marker:?} wasn't declared as a marker"))
+    }
+
+    /// Position of the first occurrence of the marker, which is the only one for
+    /// [`Count::ExactlyOne`]. Panics if it wasn't declared or wasn't found.
+    pub fn one(&self, marker: char) -> IVec2 {
+        *self
+            .all(marker)
+            .first()
+            .unwrap_or_else(|| panic!("no {marker:?} in the grid"))
+    }
+}
+
+/// Parses character maps with `map` giving the cell for every character, `None` for ones that
+/// don't belong in the map.
+///
+/// Markers still go through `map`, so the start of a maze can become a free cell while its
+/// position is kept in the [`ParsedGrid`]. Blank lines before and after the map are skipped, so
+/// example literals starting on the line after `r#"` parse as they are, and y counts from the
+/// first line of the map.
+pub struct GridParser<F> {
+    map: F,
+    markers: Vec<(char, Count)>,
+}
+
+impl<T, F: FnMut(char) -> Option<T>> GridParser<F> {
+    pub fn new(map: F) -> Self {
+        Self {
+            map,
+            markers: Vec::new(),
+        }
+    }
+
+    /// Records the positions of `marker`, and checks it shows up `count` times.
+    pub fn marker(mut self, marker: char, count: Count) -> Self {
+        self.markers.push((marker, count));
+        self
+    }
+
+    pub fn parse(mut self, input: &str) -> Result<ParsedGrid<T>, ParseError> {
+        let mut markers = self
+            .markers
+            .iter()
+            .map(|(c, _)| (*c, Vec::new()))
+            .collect::<Vec<_>>();
+
+        let mut lines = input
+            .lines()
+            .skip_while(|v| v.trim().is_empty())
+            .collect::<Vec<_>>();
+        while lines.last().is_some_and(|v| v.trim().is_empty()) {
+            lines.pop();
+        }
+
+        let mut rows = Vec::new();
+        for (y, line) in lines.into_iter().enumerate() {
+            let mut row = Vec::with_capacity(line.len());
+            for (x, c) in line.chars().enumerate() {
+                if let Some((_, positions)) = markers.iter_mut().find(|(m, _)| *m == c) {
+                    positions.push(IVec2 {
+                        x: x as i64,
+                        y: y as i64,
+                    });
+                }
+                row.push((self.map)(c).ok_or(ParseError::UnknownChar { c, x, y })?);
+            }
+
+            if let Some(first) = rows.first().map(Vec::len) {
+                if row.len() != first {
+                    return Err(ParseError::RaggedRow {
+                        y,
+                        width: row.len(),
+                        expected: first,
+                    });
+                }
+            }
+            rows.push(row);
+        }
+
+        for ((marker, positions), (_, expected)) in markers.iter().zip(&self.markers) {
+            if !expected.allows(positions.len()) {
+                return Err(ParseError::MarkerCount {
+                    marker: *marker,
+                    expected: *expected,
+                    found: positions.len(),
+                });
+            }
+        }
+
+        Ok(ParsedGrid {
+            grid: Grid::from_rows(rows),
+            markers,
+        })
+    }
+}
@@ -0,0 +1,126 @@
+//! The one way puzzle inputs get loaded, so every day sees the same shape of text no matter how
+//! the file was saved or how an example literal starts.
+//!
+//! After [`normalize`] the text has `\n` line endings, no byte order mark, no whitespace at the
+//! end of any line, and neither blank lines at the start nor a newline at the end.
+
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+};
+
+use crate::debug;
+
+/// What [`normalize`] had to change, the newline ending the last line doesn't count.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Changes {
+    pub byte_order_mark: bool,
+    /// Number of `\r\n` line endings turned into `\n`.
+    pub crlf_line_endings: usize,
+    /// Number of lines that had spaces or tabs at the end.
+    pub trailing_whitespace: usize,
+    pub leading_blank_lines: usize,
+    pub trailing_blank_lines: usize,
+}
+
+impl Changes {
+    pub fn is_empty(&self) -> bool {
+        *self == Changes::default()
+    }
+}
+
+impl Display for Changes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        if self.byte_order_mark {
+            parts.push("removed the byte order mark".to_string());
+        }
+        let counts = [
+            (self.crlf_line_endings, "converted", "CRLF line ending"),
+            (self.trailing_whitespace, "trimmed whitespace after", "line"),
+            (self.leading_blank_lines, "removed", "leading blank line"),
+            (self.trailing_blank_lines, "removed", "trailing blank line"),
+        ];
+        for (count, action, what) in counts {
+            if count > 0 {
+                let plural = if count == 1 { "" } else { "s" };
+                parts.push(format!("{action} {count} {what}{plural}"));
+            }
+        }
+
+        if parts.is_empty() {
+            f.write_str("no changes")
+        } else {
+            f.write_str(&parts.join(", "))
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Normalized<'a> {
+    /// Borrowed from the input when nothing but the last newline had to go.
+    pub text: Cow<'a, str>,
+    pub changes: Changes,
+}
+
+pub fn normalize(input: &str) -> Normalized<'_> {
+    let mut changes = Changes::default();
+
+    let mut rest = input;
+    if let Some(v) = rest.strip_prefix('\u{feff}') {
+        changes.byte_order_mark = true;
+        rest = v;
+    }
+
+    // The newline ending the last line is expected, not a blank line after it
+    let body = rest.strip_suffix('\n').unwrap_or(rest);
+    if body.is_empty() {
+        return Normalized {
+            text: Cow::Borrowed(body),
+            changes,
+        };
+    }
+
+    let mut lines = body
+        .split('\n')
+        .map(|line| {
+            let line = match line.strip_suffix('\r') {
+                Some(v) => {
+                    changes.crlf_line_endings += 1;
+                    v
+                }
+                None => line,
+            };
+            let trimmed = line.trim_end_matches([' ', '\t']);
+            if trimmed.len() != line.len() {
+                changes.trailing_whitespace += 1;
+            }
+            trimmed
+        })
+        .collect::<Vec<_>>();
+
+    let leading = lines.iter().take_while(|v| v.is_empty()).count();
+    lines.drain(..leading);
+    changes.leading_blank_lines = leading;
+
+    let trailing = lines.iter().rev().take_while(|v| v.is_empty()).count();
+    lines.truncate(lines.len() - trailing);
+    changes.trailing_blank_lines = trailing;
+
+    let text = lines.join("\n");
+    let text = match input.get(..text.len()) {
+        Some(prefix) if prefix == text => Cow::Borrowed(prefix),
+        _ => Cow::Owned(text),
+    };
+
+    Normalized { text, changes }
+}
+
+/// [`normalize`]s the input and logs what it changed at debug level.
+pub fn load(input: &str) -> Cow<'_, str> {
+    let normalized = normalize(input);
+    if !normalized.changes.is_empty() {
+        debug!("input: {}", normalized.changes);
+    }
+    normalized.text
+}
@@ -1,5 +1,29 @@
 use std::ops::{Add, AddAssign, Sub, SubAssign};
 
+#[cfg(feature = "alloc-counter")]
+pub mod alloc;
+pub mod bench;
+pub mod bigint;
+pub mod bits;
+pub mod cancel;
+pub mod geometry;
+pub mod grid;
+pub mod grid_parser;
+pub mod hash;
+pub mod input;
+pub mod json;
+pub mod log;
+pub mod math;
+pub mod occupancy;
+pub mod path_arena;
+pub mod progress;
+pub mod rational;
+pub mod render;
+pub mod search;
+pub mod simulation;
+pub mod snapshot;
+pub mod sparse_grid;
+
 #[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq)]
 pub struct IVec2 {
     pub x: i64,
@@ -0,0 +1,149 @@
+//! Leveled logging to stderr with the [`error!`](crate::error), [`warn!`](crate::warn),
+//! [`info!`](crate::info), [`debug!`](crate::debug) and [`trace!`](crate::trace) macros.
+//!
+//! The level defaults to [`Level::Info`] and can be changed with the `AOC_LOG` environment
+//! variable (`error`, `warn`, `info`, `debug` or `trace`), or `-q`, `-v` and `-vv` on the command
+//! line. Messages below the level aren't even formatted, so logging in hot loops is cheap when
+//! it's turned off.
+
+use std::{
+    fmt::Arguments,
+    io::Write,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+/// Environment variable holding the level, e.g `AOC_LOG=debug`.
+pub const ENV_VAR: &str = "AOC_LOG";
+
+/// Marks the level as not read from the environment yet.
+const UNSET: u8 = u8::MAX;
+
+static LEVEL: AtomicU8 = AtomicU8::new(UNSET);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    const ALL: [Level; 5] = [
+        Level::Error,
+        Level::Warn,
+        Level::Info,
+        Level::Debug,
+        Level::Trace,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Level::Error => "error",
+            Level::Warn => "warn",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Level> {
+        Self::ALL
+            .into_iter()
+            .find(|v| v.name().eq_ignore_ascii_case(s.trim()))
+    }
+
+    /// The level for `verbose` times `-v`, or just errors when `quiet`.
+    pub fn from_verbosity(verbose: u8, quiet: bool) -> Level {
+        if quiet {
+            return Level::Error;
+        }
+
+        Self::ALL[(Level::Info as usize + verbose as usize).min(Self::ALL.len() - 1)]
+    }
+}
+
+/// The current level, read from [`ENV_VAR`] on first use.
+pub fn level() -> Level {
+    match LEVEL.load(Ordering::Relaxed) {
+        UNSET => {
+            let level = std::env::var(ENV_VAR)
+                .ok()
+                .and_then(|v| Level::parse(&v))
+                .unwrap_or(Level::Info);
+            // Don't overwrite a level that got set in the meantime
+            let _ =
+                LEVEL.compare_exchange(UNSET, level as u8, Ordering::Relaxed, Ordering::Relaxed);
+            self::level()
+        }
+        v => Level::ALL[v as usize],
+    }
+}
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn enabled(level: Level) -> bool {
+    level <= self::level()
+}
+
+/// Sets the level from `-q`, `-v` and `-vv` in the process arguments, if there are any.
+pub fn init_from_args() {
+    let mut verbose = 0;
+    let mut quiet = false;
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "-v" => verbose += 1,
+            "-vv" => verbose += 2,
+            "-q" => quiet = true,
+            _ => {}
+        }
+    }
+
+    if verbose > 0 || quiet {
+        set_level(Level::from_verbosity(verbose, quiet));
+    }
+}
+
+#[doc(hidden)]
+pub fn write(level: Level, args: Arguments) {
+    let _ = writeln!(std::io::stderr().lock(), "[{}] {args}", level.name());
+}
+
+/// Logs a message at the given [`Level`](crate::log::Level), formatting it like `format!`.
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {{
+        let level = $level;
+        if $crate::log::enabled(level) {
+            $crate::log::write(level, format_args!($($arg)+));
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Error, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
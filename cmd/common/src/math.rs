@@ -0,0 +1,154 @@
+//! Number theory on the signed integer types, mostly `i64` and `i128`.
+//!
+//! Results are normalised to be non-negative where a sign would be arbitrary, e.g [`gcd`] and
+//! the residues returned by [`mod_inverse`] and [`crt`].
+
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/// The signed integer types the functions in this module work on.
+pub trait Int:
+    Copy
+    + Ord
+    + std::fmt::Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const TWO: Self;
+
+    fn abs(self) -> Self;
+    fn rem_euclid(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_int {
+    ($($t:ty),*) => {
+        $(
+            impl Int for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const TWO: Self = 2;
+
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+
+                fn rem_euclid(self, rhs: Self) -> Self {
+                    <$t>::rem_euclid(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_int!(i8, i16, i32, i64, i128, isize);
+
+/// Greatest common divisor, always non-negative, `gcd(0, 0)` is 0.
+pub fn gcd<T: Int>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple, always non-negative, 0 if either is 0.
+pub fn lcm<T: Int>(a: T, b: T) -> T {
+    if a == T::ZERO || b == T::ZERO {
+        return T::ZERO;
+    }
+    (a / gcd(a, b) * b).abs()
+}
+
+/// Returns `(g, x, y)` with `a * x + b * y == g` and `g == gcd(a, b)`.
+pub fn extended_gcd<T: Int>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+
+    while r != T::ZERO {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    if old_r < T::ZERO {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The `x` in `0..m` with `a * x ≡ 1 (mod m)`, `None` unless `a` and `m` are coprime.
+pub fn mod_inverse<T: Int>(a: T, m: T) -> Option<T> {
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    (g == T::ONE).then(|| x.rem_euclid(m))
+}
+
+/// `base.pow(exp) % m` in `0..m` by squaring, `exp` has to be non-negative.
+///
+/// Intermediate products are `(m - 1)²`, so `m` must stay below the square root of `T::MAX`, use
+/// `i128` for moduli above `i32::MAX`.
+pub fn mod_pow<T: Int>(base: T, exp: T, m: T) -> T {
+    assert!(exp >= T::ZERO, "negative exponent {exp:?}");
+
+    let mut result = T::ONE.rem_euclid(m);
+    let mut base = base.rem_euclid(m);
+    let mut exp = exp;
+    while exp > T::ZERO {
+        if exp % T::TWO == T::ONE {
+            result = result * base % m;
+        }
+        base = base * base % m;
+        exp = exp / T::TWO;
+    }
+    result
+}
+
+/// Solves `x ≡ r (mod m)` for every `(r, m)` at once, the moduli don't have to be coprime.
+///
+/// Returns `(x, lcm)` with `x` in `0..lcm` where `lcm` is the least common multiple of the moduli,
+/// or `None` when the congruences contradict each other.
+pub fn crt<T: Int>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    let mut x = T::ZERO;
+    let mut m = T::ONE;
+
+    for &(r, n) in congruences {
+        // x + m * k ≡ r (mod n), solvable when gcd(m, n) divides r - x
+        let (g, p, _) = extended_gcd(m, n);
+        let diff = r - x;
+        if diff % g != T::ZERO {
+            return None;
+        }
+
+        let n_g = n / g;
+        let k = (diff / g % n_g * p).rem_euclid(n_g);
+        x = x + m * k;
+        m = m * n_g;
+        x = x.rem_euclid(m);
+    }
+
+    Some((x, m))
+}
+
+/// Floor of the square root, panics on negative numbers.
+pub fn isqrt<T: Int>(n: T) -> T {
+    assert!(n >= T::ZERO, "square root of negative {n:?}");
+    if n < T::TWO {
+        return n;
+    }
+
+    // Newton's method from above, n / 2 + 1 is at least the root and can't overflow
+    let mut x = n / T::TWO + T::ONE;
+    let mut y = (x + n / x) / T::TWO;
+    while y < x {
+        x = y;
+        y = (x + n / x) / T::TWO;
+    }
+    x
+}
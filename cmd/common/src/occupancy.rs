@@ -0,0 +1,199 @@
+//! How many entities stand on each cell of a bounded grid, for days where lots of things move
+//! around and the board gets queried per cell.
+
+use crate::IVec2;
+
+/// Entity counts per cell of a `width` by `height` grid, kept up to date as entities move.
+///
+/// Counting, adding, removing and moving are O(1). Positions off the grid count as empty and
+/// panic when added to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OccupancyGrid {
+    width: i64,
+    height: i64,
+    counts: Vec<u32>,
+    /// Number of cells with a count above zero.
+    occupied: usize,
+}
+
+impl OccupancyGrid {
+    pub fn new(width: i64, height: i64) -> Self {
+        assert!(
+            width >= 0 && height >= 0,
+            "negative grid size {width}x{height}"
+        );
+        Self {
+            width,
+            height,
+            counts: vec![0; (width * height) as usize],
+            occupied: 0,
+        }
+    }
+
+    /// Grid with an entity at every position, duplicates stack up.
+    pub fn from_positions(
+        width: i64,
+        height: i64,
+        positions: impl IntoIterator<Item = IVec2>,
+    ) -> Self {
+        let mut grid = Self::new(width, height);
+        for pos in positions {
+            grid.add(pos);
+        }
+        grid
+    }
+
+    pub fn width(&self) -> i64 {
+        self.width
+    }
+
+    pub fn height(&self) -> i64 {
+        self.height
+    }
+
+    pub fn in_bounds(&self, pos: IVec2) -> bool {
+        pos.x >= 0 && pos.y >= 0 && pos.x < self.width && pos.y < self.height
+    }
+
+    /// Puts one more entity on the cell.
+    pub fn add(&mut self, pos: IVec2) {
+        let index = self.index(pos);
+        if self.counts[index] == 0 {
+            self.occupied += 1;
+        }
+        self.counts[index] += 1;
+    }
+
+    /// Takes one entity off the cell, returns whether there was one.
+    pub fn remove(&mut self, pos: IVec2) -> bool {
+        let index = self.index(pos);
+        match self.counts[index] {
+            0 => false,
+            1 => {
+                self.counts[index] = 0;
+                self.occupied -= 1;
+                true
+            }
+            _ => {
+                self.counts[index] -= 1;
+                true
+            }
+        }
+    }
+
+    /// Moves one entity between cells, panics if there's none at `from`.
+    pub fn move_entity(&mut self, from: IVec2, to: IVec2) {
+        assert!(self.remove(from), "no entity at {from:?} to move");
+        self.add(to);
+    }
+
+    /// Number of entities on the cell, 0 off the grid.
+    pub fn count_at(&self, pos: IVec2) -> u32 {
+        if !self.in_bounds(pos) {
+            return 0;
+        }
+        self.counts[self.index(pos)]
+    }
+
+    pub fn is_occupied(&self, pos: IVec2) -> bool {
+        self.count_at(pos) > 0
+    }
+
+    /// Number of cells with at least one entity.
+    pub fn occupied_count(&self) -> usize {
+        self.occupied
+    }
+
+    /// Total number of entities on the grid.
+    pub fn total(&self) -> u64 {
+        self.counts.iter().map(|v| *v as u64).sum()
+    }
+
+    /// Cells within `radius` steps of `pos` in every direction including diagonals, with their
+    /// counts. Cells off the grid are skipped, so this is a `2 * radius + 1` square at most.
+    pub fn neighbourhood(
+        &self,
+        pos: IVec2,
+        radius: i64,
+    ) -> impl Iterator<Item = (IVec2, u32)> + '_ {
+        (pos.y - radius..=pos.y + radius)
+            .flat_map(move |y| (pos.x - radius..=pos.x + radius).map(move |x| IVec2 { x, y }))
+            .filter(|v| self.in_bounds(*v))
+            .map(|v| (v, self.counts[self.index(v)]))
+    }
+
+    /// Number of entities within `radius` of `pos`, see [`OccupancyGrid::neighbourhood`].
+    pub fn count_around(&self, pos: IVec2, radius: i64) -> u32 {
+        self.neighbourhood(pos, radius)
+            .map(|(_, count)| count)
+            .sum()
+    }
+
+    /// Number of occupied cells within `radius` of `pos`, see [`OccupancyGrid::neighbourhood`].
+    pub fn occupied_around(&self, pos: IVec2, radius: i64) -> usize {
+        self.neighbourhood(pos, radius)
+            .filter(|(_, count)| *count > 0)
+            .count()
+    }
+
+    /// Number of entities per quadrant in reading order, top left, top right, bottom left and
+    /// bottom right.
+    ///
+    /// On an odd size the middle column or row belongs to no quadrant and isn't counted.
+    pub fn quadrant_counts(&self) -> [u32; 4] {
+        let (left, top) = (self.width / 2, self.height / 2);
+        let (right, bottom) = ((self.width + 1) / 2, (self.height + 1) / 2);
+
+        let mut quadrants = [0; 4];
+        for (pos, count) in self.occupied() {
+            let column = match pos.x {
+                x if x < left => 0,
+                x if x >= right => 1,
+                _ => continue,
+            };
+            let row = match pos.y {
+                y if y < top => 0,
+                y if y >= bottom => 2,
+                _ => continue,
+            };
+            quadrants[row + column] += count;
+        }
+        quadrants
+    }
+
+    /// Occupied cells with their counts, row by row.
+    pub fn occupied(&self) -> impl Iterator<Item = (IVec2, u32)> + '_ {
+        self.counts
+            .iter()
+            .enumerate()
+            .filter(|(_, count)| **count > 0)
+            .map(|(i, count)| {
+                let i = i as i64;
+                (
+                    IVec2 {
+                        x: i % self.width,
+                        y: i / self.width,
+                    },
+                    *count,
+                )
+            })
+    }
+
+    /// Removes every entity while keeping the allocation.
+    pub fn clear(&mut self) {
+        self.counts.fill(0);
+        self.occupied = 0;
+    }
+
+    fn index(&self, pos: IVec2) -> usize {
+        assert!(
+            self.in_bounds(pos),
+            "cell {}, {} out of range for {}x{} occupancy grid",
+            pos.x,
+            pos.y,
+            self.width,
+            self.height
+        );
+        (pos.y * self.width + pos.x) as usize
+    }
+}
@@ -0,0 +1,105 @@
+//! Paths for searches that keep branching, stored as links back to the previous step so
+//! branches share everything up to where they split instead of each owning a copy.
+
+/// Handle to a path in a [`PathArena`], cheap to copy so a branch is just another handle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PathId(u32);
+
+#[derive(Debug, Clone)]
+struct Node<T> {
+    value: T,
+    parent: Option<PathId>,
+    len: u32,
+}
+
+/// Owns every step of every path, paths are never freed before the arena itself.
+///
+/// Extending a path is O(1) and leaves the path it was extended from untouched, walking a path
+/// is O(len).
+#[derive(Debug, Clone)]
+pub struct PathArena<T> {
+    nodes: Vec<Node<T>>,
+}
+
+impl<T> Default for PathArena<T> {
+    fn default() -> Self {
+        Self { nodes: Vec::new() }
+    }
+}
+
+impl<T> PathArena<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A new path of just `value`.
+    pub fn start(&mut self, value: T) -> PathId {
+        self.push(value, None)
+    }
+
+    /// The path `path` followed by `value`, `path` itself stays as it was.
+    pub fn extend(&mut self, path: PathId, value: T) -> PathId {
+        self.push(value, Some(path))
+    }
+
+    /// The path without its last step, `None` for a path of one step.
+    pub fn parent(&self, path: PathId) -> Option<PathId> {
+        self.node(path).parent
+    }
+
+    pub fn last(&self, path: PathId) -> &T {
+        &self.node(path).value
+    }
+
+    /// Number of steps in the path.
+    pub fn len(&self, path: PathId) -> usize {
+        self.node(path).len as usize
+    }
+
+    /// The steps from last to first.
+    pub fn iter(&self, path: PathId) -> impl Iterator<Item = &T> + '_ {
+        let mut next = Some(path);
+        std::iter::from_fn(move || {
+            let node = self.node(next?);
+            next = node.parent;
+            Some(&node.value)
+        })
+    }
+
+    /// Number of steps stored for all paths together.
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    fn push(&mut self, value: T, parent: Option<PathId>) -> PathId {
+        let id = PathId(
+            self.nodes
+                .len()
+                .try_into()
+                .expect("path arena holds at most u32::MAX steps"),
+        );
+        let len = parent.map_or(1, |v| self.node(v).len + 1);
+        self.nodes.push(Node { value, parent, len });
+        id
+    }
+
+    fn node(&self, path: PathId) -> &Node<T> {
+        &self.nodes[path.0 as usize]
+    }
+}
+
+impl<T: PartialEq> PathArena<T> {
+    /// Whether any step of the path is `value`.
+    pub fn contains(&self, path: PathId, value: &T) -> bool {
+        self.iter(path).any(|v| v == value)
+    }
+}
+
+impl<T: Clone> PathArena<T> {
+    /// The steps from first to last as their own `Vec`.
+    pub fn to_vec(&self, path: PathId) -> Vec<T> {
+        let mut steps = self.iter(path).cloned().collect::<Vec<_>>();
+        steps.reverse();
+        steps
+    }
+}
@@ -0,0 +1,118 @@
+//! Progress of long loops, drawn as a single line on stderr that keeps getting overwritten.
+//!
+//! Nothing gets drawn when stderr isn't a terminal, so piping the output stays clean.
+
+use std::{
+    io::{IsTerminal, Write},
+    time::{Duration, Instant},
+};
+
+/// How often the line gets redrawn at most.
+const REDRAW_INTERVAL: Duration = Duration::from_millis(100);
+
+pub struct Progress {
+    label: &'static str,
+    /// Number of steps in total, `None` when it isn't known upfront.
+    total: Option<u64>,
+    current: u64,
+    started: Instant,
+    last_draw: Option<Instant>,
+    enabled: bool,
+}
+
+impl Progress {
+    /// Progress towards `total` steps, showing the rate and an ETA.
+    pub fn new(label: &'static str, total: u64) -> Self {
+        Self::with_total(label, Some(total))
+    }
+
+    /// Progress of a loop without a known end, only showing the count and rate.
+    pub fn unbounded(label: &'static str) -> Self {
+        Self::with_total(label, None)
+    }
+
+    fn with_total(label: &'static str, total: Option<u64>) -> Self {
+        Self {
+            label,
+            total,
+            current: 0,
+            started: Instant::now(),
+            last_draw: None,
+            enabled: std::io::stderr().is_terminal(),
+        }
+    }
+
+    /// Counts one step done.
+    pub fn inc(&mut self) {
+        self.set(self.current + 1);
+    }
+
+    /// Counts one step done, `detail` is shown after the count and only called when redrawing.
+    pub fn inc_with(&mut self, detail: impl FnOnce() -> String) {
+        self.current += 1;
+        if self.should_draw() {
+            self.draw(&detail());
+        }
+    }
+
+    pub fn set(&mut self, current: u64) {
+        self.current = current;
+        if self.should_draw() {
+            self.draw("");
+        }
+    }
+
+    fn should_draw(&self) -> bool {
+        self.enabled
+            && self
+                .last_draw
+                .is_none_or(|v| v.elapsed() >= REDRAW_INTERVAL)
+    }
+
+    fn draw(&mut self, detail: &str) {
+        self.last_draw = Some(Instant::now());
+
+        let elapsed = self.started.elapsed().as_secs_f64();
+        let rate = if elapsed > 0.0 {
+            self.current as f64 / elapsed
+        } else {
+            0.0
+        };
+
+        let mut line = match self.total {
+            Some(total) => {
+                let percent = self.current as f64 / total.max(1) as f64 * 100.0;
+                let eta = if rate > 0.0 {
+                    format!("{:.1}s", total.saturating_sub(self.current) as f64 / rate)
+                } else {
+                    "?".to_string()
+                };
+                format!(
+                    "{}: {}/{total} ({percent:.0}%), {rate:.0}/s, ETA {eta}",
+                    self.label, self.current
+                )
+            }
+            None => format!("{}: {}, {rate:.0}/s", self.label, self.current),
+        };
+
+        if !detail.is_empty() {
+            line.push_str(", ");
+            line.push_str(detail);
+        }
+
+        // Carriage return and erase the line, so shorter lines don't leave anything behind
+        let mut stderr = std::io::stderr().lock();
+        let _ = write!(stderr, "\r\x1b[2K{line}");
+        let _ = stderr.flush();
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        if self.enabled && self.last_draw.is_some() {
+            let mut stderr = std::io::stderr().lock();
+            let _ = write!(stderr, "\r\x1b[2K");
+            let _ = stderr.flush();
+        }
+    }
+}
@@ -0,0 +1,187 @@
+//! Exact fractions of `i128`s, for math that has to come out as whole numbers where `f64` would
+//! round.
+
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use crate::math::gcd;
+
+/// A fraction kept in lowest terms with a positive denominator, so equal values compare equal
+/// field by field.
+///
+/// Arithmetic overflows like the integer types do, which takes values around `10^19` on both
+/// sides of an operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numer: i128,
+    denom: i128,
+}
+
+impl Rational {
+    pub const ZERO: Rational = Rational { numer: 0, denom: 1 };
+    pub const ONE: Rational = Rational { numer: 1, denom: 1 };
+
+    /// `numer / denom` in lowest terms, panics if `denom` is 0.
+    pub fn new(numer: i128, denom: i128) -> Self {
+        assert!(denom != 0, "rational with a zero denominator");
+
+        let g = gcd(numer, denom);
+        let sign = denom.signum();
+        Self {
+            numer: numer / g * sign,
+            denom: denom / g * sign,
+        }
+    }
+
+    pub fn numer(&self) -> i128 {
+        self.numer
+    }
+
+    /// Always positive.
+    pub fn denom(&self) -> i128 {
+        self.denom
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denom == 1
+    }
+
+    /// The value as an integer if it is one.
+    pub fn to_integer(&self) -> Option<i128> {
+        self.is_integer().then_some(self.numer)
+    }
+
+    /// Rounds towards negative infinity.
+    pub fn floor(&self) -> i128 {
+        self.numer.div_euclid(self.denom)
+    }
+
+    /// Rounds towards positive infinity.
+    pub fn ceil(&self) -> i128 {
+        -(-*self).floor()
+    }
+
+    pub fn abs(&self) -> Rational {
+        Self {
+            numer: self.numer.abs(),
+            denom: self.denom,
+        }
+    }
+
+    /// `1 / self`, panics for 0.
+    pub fn recip(&self) -> Rational {
+        Rational::new(self.denom, self.numer)
+    }
+}
+
+impl Default for Rational {
+    fn default() -> Self {
+        Rational::ZERO
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
+
+macro_rules! from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Rational {
+                fn from(v: $t) -> Self {
+                    Rational {
+                        numer: v as i128,
+                        denom: 1,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+from_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Denominators are positive, so cross multiplying keeps the order
+        (self.numer * other.denom).cmp(&(other.numer * self.denom))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            numer: -self.numer,
+            denom: self.denom,
+        }
+    }
+}
+
+impl Add for Rational {
+    type Output = Rational;
+
+    fn add(self, rhs: Rational) -> Self::Output {
+        // Over the lcm of the denominators rather than their product, to stay further from
+        // overflowing
+        let g = gcd(self.denom, rhs.denom);
+        Rational::new(
+            self.numer * (rhs.denom / g) + rhs.numer * (self.denom / g),
+            self.denom / g * rhs.denom,
+        )
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+
+    fn sub(self, rhs: Rational) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, rhs: Rational) -> Self::Output {
+        // Cancel crosswise first, the results are already in lowest terms then
+        let g1 = gcd(self.numer, rhs.denom);
+        let g2 = gcd(rhs.numer, self.denom);
+        Rational::new(
+            (self.numer / g1) * (rhs.numer / g2),
+            (self.denom / g2) * (rhs.denom / g1),
+        )
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+
+    /// Panics when dividing by 0.
+    fn div(self, rhs: Rational) -> Self::Output {
+        assert!(rhs.numer != 0, "division of a rational by zero");
+
+        let g1 = gcd(self.numer, rhs.numer);
+        let g2 = gcd(self.denom, rhs.denom);
+        Rational::new(
+            (self.numer / g1) * (rhs.denom / g2),
+            (self.denom / g2) * (rhs.numer / g1),
+        )
+    }
+}
@@ -0,0 +1,322 @@
+//! Grids drawn with ANSI colours, highlighted cells and a legend, falling back to plain text
+//! when stdout isn't a terminal or `NO_COLOR` is set.
+//!
+//! A [`Renderer`] with colour turned off draws exactly the cell texts, so the same code can
+//! print to a terminal and produce snapshot output.
+
+use std::{ffi::OsStr, fmt::Write, io::IsTerminal};
+
+use crate::{grid::Grid, hash::FastSet};
+
+/// The 16 colours every ANSI terminal has, the actual shades depend on its theme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Gray,
+    BrightRed,
+    BrightGreen,
+    BrightYellow,
+    BrightBlue,
+    BrightMagenta,
+    BrightCyan,
+    BrightWhite,
+}
+
+impl Color {
+    /// Colours that are easy to tell apart, for colouring things by id.
+    pub const PALETTE: [Color; 12] = [
+        Color::Red,
+        Color::Green,
+        Color::Yellow,
+        Color::Blue,
+        Color::Magenta,
+        Color::Cyan,
+        Color::BrightRed,
+        Color::BrightGreen,
+        Color::BrightYellow,
+        Color::BrightBlue,
+        Color::BrightMagenta,
+        Color::BrightCyan,
+    ];
+
+    /// A colour from [`Color::PALETTE`], the same one for the same id.
+    pub fn for_id(id: usize) -> Color {
+        Self::PALETTE[id % Self::PALETTE.len()]
+    }
+
+    fn foreground_code(self) -> u8 {
+        match self {
+            Color::Black => 30,
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+            Color::White => 37,
+            Color::Gray => 90,
+            Color::BrightRed => 91,
+            Color::BrightGreen => 92,
+            Color::BrightYellow => 93,
+            Color::BrightBlue => 94,
+            Color::BrightMagenta => 95,
+            Color::BrightCyan => 96,
+            Color::BrightWhite => 97,
+        }
+    }
+
+    fn background_code(self) -> u8 {
+        self.foreground_code() + 10
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Style {
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+    pub bold: bool,
+}
+
+impl Style {
+    pub fn fg(mut self, color: Color) -> Self {
+        self.fg = Some(color);
+        self
+    }
+
+    pub fn bg(mut self, color: Color) -> Self {
+        self.bg = Some(color);
+        self
+    }
+
+    pub fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    /// This style with whatever `top` sets drawn over it.
+    pub fn under(self, top: Style) -> Style {
+        Style {
+            fg: top.fg.or(self.fg),
+            bg: top.bg.or(self.bg),
+            bold: self.bold || top.bold,
+        }
+    }
+
+    /// The SGR escape sequence switching to this style from the default one.
+    fn escape(&self) -> String {
+        let mut codes = vec!["0".to_string()];
+        if self.bold {
+            codes.push("1".to_string());
+        }
+        if let Some(fg) = self.fg {
+            codes.push(fg.foreground_code().to_string());
+        }
+        if let Some(bg) = self.bg {
+            codes.push(bg.background_code().to_string());
+        }
+        format!("\x1b[{}m", codes.join(";"))
+    }
+}
+
+/// What gets drawn for one grid cell, usually a single character.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Cell {
+    pub text: String,
+    pub style: Style,
+}
+
+impl Cell {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            style: Style::default(),
+        }
+    }
+
+    pub fn styled(text: impl Into<String>, style: Style) -> Self {
+        Self {
+            text: text.into(),
+            style,
+        }
+    }
+}
+
+impl From<char> for Cell {
+    fn from(c: char) -> Self {
+        Cell::new(c)
+    }
+}
+
+impl From<&str> for Cell {
+    fn from(s: &str) -> Self {
+        Cell::new(s)
+    }
+}
+
+/// Whether to colour stdout, which is when it's a terminal and `NO_COLOR` isn't set to anything.
+pub fn color_enabled() -> bool {
+    color_enabled_from(
+        std::env::var_os("NO_COLOR").as_deref(),
+        std::io::stdout().is_terminal(),
+    )
+}
+
+/// [`color_enabled`] for the given value of `NO_COLOR` and whether stdout is a terminal.
+pub fn color_enabled_from(no_color: Option<&OsStr>, is_tty: bool) -> bool {
+    no_color.is_none_or(|v| v.is_empty()) && is_tty
+}
+
+/// A set of cells drawn differently, e.g a path or a region.
+struct Overlay {
+    cells: FastSet<(usize, usize)>,
+    style: Style,
+    /// Replaces the cell text, so the overlay still shows without colour.
+    glyph: Option<String>,
+}
+
+/// Draws grids cell by cell, see the module docs.
+pub struct Renderer {
+    color: bool,
+    overlays: Vec<Overlay>,
+    legend: Vec<(Cell, String)>,
+}
+
+impl Default for Renderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Renderer {
+    /// Colours if [`color_enabled`].
+    pub fn new() -> Self {
+        Self {
+            color: color_enabled(),
+            overlays: Vec::new(),
+            legend: Vec::new(),
+        }
+    }
+
+    /// Turns colour on or off regardless of the terminal.
+    pub fn color(mut self, enabled: bool) -> Self {
+        self.color = enabled;
+        self
+    }
+
+    /// Draws `style` over the given cells, keeping their text, so it only shows with colour.
+    ///
+    /// Later overlays are drawn over earlier ones. Adds a legend entry with `label`.
+    pub fn highlight(
+        self,
+        label: &str,
+        cells: impl IntoIterator<Item = (usize, usize)>,
+        style: Style,
+    ) -> Self {
+        self.overlay(label, cells, style, None)
+    }
+
+    /// Like [`Renderer::highlight`], but also replaces the text of the cells with `glyph` so
+    /// they stand out without colour too.
+    pub fn mark(
+        self,
+        label: &str,
+        cells: impl IntoIterator<Item = (usize, usize)>,
+        glyph: impl Into<String>,
+        style: Style,
+    ) -> Self {
+        self.overlay(label, cells, style, Some(glyph.into()))
+    }
+
+    /// Adds a line to the legend printed below the grid.
+    pub fn legend(mut self, sample: impl Into<Cell>, label: &str) -> Self {
+        self.legend.push((sample.into(), label.to_string()));
+        self
+    }
+
+    /// Draws a `width` by `height` grid with `cell(x, y)` for every cell, followed by the
+    /// legend if there is one.
+    pub fn render(
+        &self,
+        width: usize,
+        height: usize,
+        mut cell: impl FnMut(usize, usize) -> Cell,
+    ) -> String {
+        let mut out = String::new();
+        for y in 0..height {
+            let mut current = Style::default();
+            for x in 0..width {
+                let cell = self.apply_overlays(x, y, cell(x, y));
+                self.push_cell(&mut out, &cell, &mut current);
+            }
+            if current != Style::default() {
+                out.push_str("\x1b[0m");
+            }
+            out.push('\n');
+        }
+
+        for (sample, label) in &self.legend {
+            // A sample that's only a background colour means nothing in plain text
+            if !self.color && sample.text.trim().is_empty() {
+                continue;
+            }
+            let mut current = Style::default();
+            self.push_cell(&mut out, sample, &mut current);
+            if current != Style::default() {
+                out.push_str("\x1b[0m");
+            }
+            writeln!(out, " {label}").unwrap();
+        }
+
+        out
+    }
+
+    /// [`Renderer::render`] for every cell of a grid.
+    pub fn render_grid<T>(&self, grid: &Grid<T>, mut cell: impl FnMut(&T) -> Cell) -> String {
+        self.render(grid.width(), grid.height(), |x, y| cell(&grid[(x, y)]))
+    }
+
+    fn overlay(
+        mut self,
+        label: &str,
+        cells: impl IntoIterator<Item = (usize, usize)>,
+        style: Style,
+        glyph: Option<String>,
+    ) -> Self {
+        let sample = Cell::styled(glyph.clone().unwrap_or_else(|| " ".to_string()), style);
+        self.legend.push((sample, label.to_string()));
+        self.overlays.push(Overlay {
+            cells: cells.into_iter().collect(),
+            style,
+            glyph,
+        });
+        self
+    }
+
+    fn apply_overlays(&self, x: usize, y: usize, mut cell: Cell) -> Cell {
+        for overlay in &self.overlays {
+            if overlay.cells.contains(&(x, y)) {
+                cell.style = cell.style.under(overlay.style);
+                if let Some(glyph) = &overlay.glyph {
+                    cell.text.clone_from(glyph);
+                }
+            }
+        }
+        cell
+    }
+
+    /// Writes the cell, switching styles only when it differs from the previous cell's.
+    fn push_cell(&self, out: &mut String, cell: &Cell, current: &mut Style) {
+        if self.color && cell.style != *current {
+            out.push_str(&cell.style.escape());
+            *current = cell.style;
+        }
+        out.push_str(&cell.text);
+    }
+}
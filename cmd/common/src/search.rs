@@ -0,0 +1,207 @@
+//! Breadth and depth first search over any graph given as start nodes and a neighbour closure,
+//! plus counting the distinct paths through a DAG.
+//!
+//! Nothing here recurses, so deep graphs don't overflow the stack.
+
+use std::{collections::VecDeque, hash::Hash};
+
+use crate::hash::{FastMap, FastSet};
+
+/// The nodes a search reached, in the order it visited them, with how it got to them.
+#[derive(Debug, Clone)]
+pub struct Search<N> {
+    order: Vec<N>,
+    depth: FastMap<N, usize>,
+    parent: FastMap<N, N>,
+}
+
+impl<N: Copy + Eq + Hash> Search<N> {
+    /// Every reached node in visiting order, the starts first.
+    pub fn order(&self) -> &[N] {
+        &self.order
+    }
+
+    /// Number of reached nodes, starts included.
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    pub fn contains(&self, node: N) -> bool {
+        self.depth.contains_key(&node)
+    }
+
+    /// Number of edges between the node and the start it was reached from, 0 for starts.
+    ///
+    /// For [`bfs`] that's the shortest distance, for [`dfs`] just the depth it was found at.
+    pub fn depth(&self, node: N) -> Option<usize> {
+        self.depth.get(&node).copied()
+    }
+
+    /// The node `node` was reached from, `None` for starts and nodes that weren't reached.
+    pub fn parent(&self, node: N) -> Option<N> {
+        self.parent.get(&node).copied()
+    }
+
+    /// The nodes from a start to `node` by following the parents back, if it was reached.
+    pub fn path_to(&self, node: N) -> Option<Vec<N>> {
+        if !self.contains(node) {
+            return None;
+        }
+
+        let mut path = vec![node];
+        let mut current = node;
+        while let Some(parent) = self.parent(current) {
+            path.push(parent);
+            current = parent;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    fn new() -> Self {
+        Self {
+            order: Vec::new(),
+            depth: FastMap::default(),
+            parent: FastMap::default(),
+        }
+    }
+
+    /// Records the node as reached, returns whether it wasn't yet.
+    fn reach(&mut self, node: N, parent: Option<N>) -> bool {
+        if self.depth.contains_key(&node) {
+            return false;
+        }
+
+        let depth = parent.map_or(0, |v| self.depth[&v] + 1);
+        self.depth.insert(node, depth);
+        if let Some(parent) = parent {
+            self.parent.insert(node, parent);
+        }
+        true
+    }
+}
+
+/// Visits everything reachable from `starts` nearest first.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(N) -> I,
+) -> Search<N>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if search.reach(start, None) {
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        search.order.push(node);
+        for next in neighbours(node) {
+            if search.reach(next, Some(node)) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    search
+}
+
+/// Visits everything reachable from `starts`, following each branch as deep as it goes before
+/// backtracking.
+pub fn dfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(N) -> I,
+) -> Search<N>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new();
+    // Nodes are only reached once popped, so the parent on the stack is the one that gets kept
+    let mut stack = starts.into_iter().map(|v| (v, None)).collect::<Vec<_>>();
+    stack.reverse();
+
+    while let Some((node, parent)) = stack.pop() {
+        if !search.reach(node, parent) {
+            continue;
+        }
+
+        search.order.push(node);
+        let first = stack.len();
+        for next in neighbours(node) {
+            if !search.contains(next) {
+                stack.push((next, Some(node)));
+            }
+        }
+        // Visit the neighbours in the order given
+        stack[first..].reverse();
+    }
+
+    search
+}
+
+/// Number of distinct paths from any of `starts` ending at each reachable node, starts count as
+/// one path of their own, once even if given more than once.
+///
+/// Paths to the nodes you're interested in are then summed up from the map, e.g every path
+/// between a set of sources and a set of sinks. Panics if a cycle is reachable, there would be
+/// infinitely many paths then.
+pub fn count_paths<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(N) -> I,
+) -> FastMap<N, u64>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut starts = starts.into_iter().collect::<Vec<_>>();
+    let mut seen = FastSet::default();
+    starts.retain(|v| seen.insert(*v));
+
+    let mut edges: FastMap<N, Vec<N>> = FastMap::default();
+    let mut incoming: FastMap<N, usize> = FastMap::default();
+    for &node in bfs(starts.iter().copied(), &mut neighbours).order() {
+        let next = neighbours(node).into_iter().collect::<Vec<_>>();
+        for &v in &next {
+            *incoming.entry(v).or_default() += 1;
+        }
+        edges.insert(node, next);
+    }
+
+    // Kahn's algorithm, a node's count is final once every edge into it is processed
+    let mut counts: FastMap<N, u64> = FastMap::default();
+    for &start in &starts {
+        *counts.entry(start).or_default() += 1;
+    }
+    let mut ready = edges
+        .keys()
+        .filter(|v| !incoming.contains_key(v))
+        .copied()
+        .collect::<Vec<_>>();
+    let mut processed = 0;
+
+    while let Some(node) = ready.pop() {
+        processed += 1;
+        let count = counts.get(&node).copied().unwrap_or(0);
+        for &next in &edges[&node] {
+            *counts.entry(next).or_default() += count;
+
+            let remaining = incoming.get_mut(&next).unwrap();
+            *remaining -= 1;
+            if *remaining == 0 {
+                ready.push(next);
+            }
+        }
+    }
+
+    assert_eq!(processed, edges.len(), "graph has a cycle");
+    counts
+}
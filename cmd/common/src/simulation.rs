@@ -0,0 +1,157 @@
+//! Step based simulations and the usual ways of running them, including finding where their
+//! states start repeating so far away steps can be reached without stepping there.
+//!
+//! The loops here check [`cancel::checkpoint`](crate::cancel::checkpoint), so a simulation that
+//! never reaches its goal or never repeats still stops at the runner's timeout.
+
+use std::hash::Hash;
+
+use crate::{cancel, debug};
+
+/// A state that evolves one step at a time, always the same way from the same state.
+pub trait Simulation {
+    type State: Clone;
+    /// Identifies a state for cycle detection, two states with the same key must evolve the same.
+    type Key: Eq + Hash;
+
+    /// Advances the state by one step.
+    fn step(&self, state: &mut Self::State);
+
+    fn key(&self, state: &Self::State) -> Self::Key;
+
+    fn step_n(&self, state: &mut Self::State, n: u64) {
+        for _ in 0..n {
+            cancel::checkpoint();
+            self.step(state);
+        }
+    }
+
+    /// Steps until `done` holds for the state, returns the number of steps taken, 0 if it holds
+    /// from the start. Loops forever if it never holds.
+    fn step_until(
+        &self,
+        state: &mut Self::State,
+        mut done: impl FnMut(&Self::State) -> bool,
+    ) -> u64 {
+        let mut steps = 0;
+        while !done(state) {
+            cancel::checkpoint();
+            self.step(state);
+            steps += 1;
+        }
+        steps
+    }
+
+    /// Finds the cycle reached from `start` with Floyd's tortoise and hare.
+    ///
+    /// Needs 2 states at a time but steps about three times as often as [`Simulation::brent`].
+    /// Loops forever if the states never repeat.
+    fn floyd(&self, start: &Self::State) -> Cycle {
+        let mut tortoise = start.clone();
+        self.step(&mut tortoise);
+        let mut hare = tortoise.clone();
+        self.step(&mut hare);
+        while self.key(&tortoise) != self.key(&hare) {
+            cancel::checkpoint();
+            self.step(&mut tortoise);
+            self.step(&mut hare);
+            self.step(&mut hare);
+        }
+
+        // The hare is now a multiple of the cycle length ahead, so walking both one step at a time
+        // from the start and the meeting point makes them meet where the cycle starts
+        let mut start_index = 0;
+        let mut tortoise = start.clone();
+        while self.key(&tortoise) != self.key(&hare) {
+            cancel::checkpoint();
+            self.step(&mut tortoise);
+            self.step(&mut hare);
+            start_index += 1;
+        }
+
+        let tortoise_key = self.key(&tortoise);
+        let mut length = 1;
+        self.step(&mut hare);
+        while self.key(&hare) != tortoise_key {
+            cancel::checkpoint();
+            self.step(&mut hare);
+            length += 1;
+        }
+
+        Cycle {
+            start: start_index,
+            length,
+        }
+    }
+
+    /// Finds the cycle reached from `start` with Brent's algorithm, which moves the tortoise to
+    /// the hare at every power of two instead of stepping it.
+    ///
+    /// Loops forever if the states never repeat.
+    fn brent(&self, start: &Self::State) -> Cycle {
+        let mut power = 1;
+        let mut length = 1;
+        let mut tortoise_key = self.key(start);
+        let mut hare = start.clone();
+        self.step(&mut hare);
+        while self.key(&hare) != tortoise_key {
+            cancel::checkpoint();
+            if power == length {
+                tortoise_key = self.key(&hare);
+                power *= 2;
+                length = 0;
+            }
+            self.step(&mut hare);
+            length += 1;
+        }
+
+        // With the hare a cycle length ahead, the two meet where the cycle starts
+        let mut start_index = 0;
+        let mut tortoise = start.clone();
+        let mut hare = start.clone();
+        self.step_n(&mut hare, length);
+        while self.key(&tortoise) != self.key(&hare) {
+            cancel::checkpoint();
+            self.step(&mut tortoise);
+            self.step(&mut hare);
+            start_index += 1;
+        }
+
+        Cycle {
+            start: start_index,
+            length,
+        }
+    }
+
+    /// The state after `n` steps from `start`, stepping at most until the cycle starts and once
+    /// around it, so `n` can be as large as `u64::MAX`.
+    ///
+    /// Loops forever if the states never repeat, use [`Simulation::step_n`] for those.
+    fn state_at(&self, start: &Self::State, n: u64) -> Self::State {
+        let cycle = self.brent(start);
+        debug!("cycle of length {} from step {}", cycle.length, cycle.start);
+
+        let mut state = start.clone();
+        self.step_n(&mut state, cycle.equivalent_step(n));
+        state
+    }
+}
+
+/// Where the states start repeating, μ and λ in the usual notation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cycle {
+    /// Index of the first state that's part of the cycle, μ.
+    pub start: u64,
+    /// Number of steps until a state on the cycle comes back, λ, at least 1.
+    pub length: u64,
+}
+
+impl Cycle {
+    /// The smallest step with the same state as step `n`.
+    pub fn equivalent_step(&self, n: u64) -> u64 {
+        if n < self.start {
+            return n;
+        }
+        self.start + (n - self.start) % self.length
+    }
+}
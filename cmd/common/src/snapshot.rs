@@ -0,0 +1,125 @@
+//! Snapshot testing of rendered output, see [`assert_snapshot!`](crate::assert_snapshot).
+//!
+//! Snapshots live in `tests/snapshots/<name>.snap` next to the crate's `Cargo.toml`. Run the
+//! tests with `UPDATE_SNAPSHOTS=1` to accept new or changed output.
+
+use std::{
+    ffi::OsStr,
+    fmt::Write,
+    path::{Path, PathBuf},
+};
+
+/// Environment variable that, when set to anything but `0`, writes snapshots instead of
+/// comparing against them.
+pub const UPDATE_ENV: &str = "UPDATE_SNAPSHOTS";
+
+/// Compares `actual` to the snapshot called `name`, panicking with a line diff on mismatch.
+#[macro_export]
+macro_rules! assert_snapshot {
+    ($name:expr, $actual:expr) => {
+        $crate::snapshot::assert_snapshot(env!("CARGO_MANIFEST_DIR"), $name, &$actual)
+    };
+}
+
+/// The function behind [`assert_snapshot!`](crate::assert_snapshot), `manifest_dir` is the
+/// directory of the crate the snapshot belongs to.
+#[track_caller]
+pub fn assert_snapshot(manifest_dir: &str, name: &str, actual: &str) {
+    let path = PathBuf::from(manifest_dir)
+        .join("tests/snapshots")
+        .join(format!("{name}.snap"));
+
+    let update = update_requested(std::env::var_os(UPDATE_ENV).as_deref());
+    if let Err(message) = check_snapshot(&path, actual, update) {
+        panic!("{message}");
+    }
+}
+
+/// Whether [`UPDATE_ENV`] having the given value asks for snapshots to be written.
+pub fn update_requested(value: Option<&OsStr>) -> bool {
+    value.is_some_and(|v| v != "0")
+}
+
+/// Compares `actual` to the snapshot at `path`, or writes it there when `update` is set and they
+/// differ. The error says what went wrong, with a line diff on mismatch.
+pub fn check_snapshot(path: &Path, actual: &str, update: bool) -> Result<(), String> {
+    let expected = std::fs::read_to_string(path).ok();
+
+    if expected.as_deref() == Some(actual) {
+        return Ok(());
+    }
+
+    if update {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|err| format!("failed creating {}: {err}", parent.display()))?;
+        }
+        std::fs::write(path, actual)
+            .map_err(|err| format!("failed writing {}: {err}", path.display()))?;
+        eprintln!("updated snapshot {}", path.display());
+        return Ok(());
+    }
+
+    match expected {
+        None => Err(format!(
+            "snapshot {} doesn't exist, rerun with {UPDATE_ENV}=1 to create it",
+            path.display()
+        )),
+        Some(expected) => Err(format!(
+            "snapshot {} doesn't match, rerun with {UPDATE_ENV}=1 to accept the new output\n{}",
+            path.display(),
+            diff_lines(&expected, actual)
+        )),
+    }
+}
+
+/// Marks a line in [`diff_lines`] that isn't followed by a newline, like `diff` does.
+pub const NO_NEWLINE_MARKER: &str = "\\ No newline at end of file";
+
+/// Line diff from `old` to `new`, removed lines are prefixed with `-` and added ones with `+`.
+///
+/// A last line without a newline is followed by [`NO_NEWLINE_MARKER`], so output that only
+/// differs in its trailing newline still shows up as a changed line.
+pub fn diff_lines(old: &str, new: &str) -> String {
+    // Lines keep their newline, so "a" and "a\n" don't compare equal
+    let old = old.split_inclusive('\n').collect::<Vec<_>>();
+    let new = new.split_inclusive('\n').collect::<Vec<_>>();
+
+    // lcs[i][j] is the length of the longest common subsequence of old[i..] and new[j..]
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut out = String::new();
+    let mut push = |prefix: char, line: &str| {
+        match line.strip_suffix('\n') {
+            Some(line) => writeln!(out, "{prefix}{line}"),
+            None => writeln!(out, "{prefix}{line}\n{NO_NEWLINE_MARKER}"),
+        }
+        .unwrap();
+    };
+
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            push(' ', old[i]);
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            push('-', old[i]);
+            i += 1;
+        } else {
+            push('+', new[j]);
+            j += 1;
+        }
+    }
+
+    out
+}
@@ -0,0 +1,225 @@
+//! Unbounded grids that only store the cells that were set, for puzzles that spread out without
+//! a known size. [`Grid`] is faster when the size is known upfront.
+
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+use crate::{grid::Grid, hash::FastMap, IVec2};
+
+/// A grid over all of `i64` by `i64` where only set cells are stored.
+///
+/// Coordinates work like [`Grid`]'s, x going right and y going down, but can be negative. The
+/// bounding box of the set cells grows and shrinks with them. Unset cells are `None` to
+/// [`SparseGrid::get`] like cells off a [`Grid`], and show up as `default` when rendering or
+/// converting to a [`Grid`].
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    cells: FastMap<IVec2, T>,
+    default: T,
+    /// Top left and bottom right set cell, inclusive, `None` while empty.
+    bounds: Option<(IVec2, IVec2)>,
+}
+
+impl<T: Default> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new(default: T) -> Self {
+        Self {
+            cells: FastMap::default(),
+            default,
+            bounds: None,
+        }
+    }
+
+    /// The cell at `(x, y)`, `None` if it isn't set.
+    pub fn get(&self, x: i64, y: i64) -> Option<&T> {
+        self.get_at(IVec2 { x, y })
+    }
+
+    pub fn get_at(&self, pos: IVec2) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    /// The cell at `(x, y)`, `None` if it isn't set.
+    pub fn get_mut(&mut self, x: i64, y: i64) -> Option<&mut T> {
+        self.cells.get_mut(&IVec2 { x, y })
+    }
+
+    /// The cell at `(x, y)`, or the default if it isn't set, without setting it.
+    pub fn cell_or_default(&self, x: i64, y: i64) -> &T {
+        self.get(x, y).unwrap_or(&self.default)
+    }
+
+    /// The cell at `(x, y)`, setting it to the default first if it isn't set.
+    pub fn get_or_default(&mut self, x: i64, y: i64) -> &mut T
+    where
+        T: Clone,
+    {
+        let pos = IVec2 { x, y };
+        self.grow_bounds(pos);
+        self.cells
+            .entry(pos)
+            .or_insert_with(|| self.default.clone())
+    }
+
+    /// Sets the cell, returns its previous value if it was set.
+    pub fn set(&mut self, x: i64, y: i64, value: T) -> Option<T> {
+        self.set_at(IVec2 { x, y }, value)
+    }
+
+    pub fn set_at(&mut self, pos: IVec2, value: T) -> Option<T> {
+        self.grow_bounds(pos);
+        self.cells.insert(pos, value)
+    }
+
+    /// Unsets the cell, returns its value if it was set.
+    pub fn remove(&mut self, x: i64, y: i64) -> Option<T> {
+        let pos = IVec2 { x, y };
+        let value = self.cells.remove(&pos)?;
+        if let Some((min, max)) = self.bounds {
+            if pos.x == min.x || pos.y == min.y || pos.x == max.x || pos.y == max.y {
+                self.recompute_bounds();
+            }
+        }
+        Some(value)
+    }
+
+    /// Whether the cell was set, even if to the default.
+    pub fn contains(&self, x: i64, y: i64) -> bool {
+        self.cells.contains_key(&IVec2 { x, y })
+    }
+
+    /// Number of set cells.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn default_value(&self) -> &T {
+        &self.default
+    }
+
+    /// Top left and bottom right corner of the smallest box around every set cell, inclusive.
+    pub fn bounds(&self) -> Option<(IVec2, IVec2)> {
+        self.bounds
+    }
+
+    /// Width of [`SparseGrid::bounds`], 0 while empty.
+    pub fn width(&self) -> usize {
+        self.bounds
+            .map_or(0, |(min, max)| (max.x - min.x + 1) as usize)
+    }
+
+    /// Height of [`SparseGrid::bounds`], 0 while empty.
+    pub fn height(&self) -> usize {
+        self.bounds
+            .map_or(0, |(min, max)| (max.y - min.y + 1) as usize)
+    }
+
+    /// Whether the position is inside [`SparseGrid::bounds`], set or not.
+    pub fn in_bounds(&self, x: i64, y: i64) -> bool {
+        self.bounds
+            .is_some_and(|(min, max)| (min.x..=max.x).contains(&x) && (min.y..=max.y).contains(&y))
+    }
+
+    /// The set cells with their `(x, y)`, in no particular order.
+    pub fn cells(&self) -> impl Iterator<Item = ((i64, i64), &T)> + '_ {
+        self.cells.iter().map(|(pos, v)| ((pos.x, pos.y), v))
+    }
+
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> SparseGrid<U> {
+        SparseGrid {
+            cells: self.cells.iter().map(|(pos, v)| (*pos, f(v))).collect(),
+            default: f(&self.default),
+            bounds: self.bounds,
+        }
+    }
+
+    /// The bounding box as a dense grid with unset cells as the default, along with the position
+    /// its top left cell had here.
+    pub fn to_grid(&self) -> (Grid<T>, IVec2)
+    where
+        T: Clone,
+    {
+        let origin = self.bounds.map_or(IVec2::default(), |(min, _)| min);
+        let grid = Grid::from_fn(self.width(), self.height(), |x, y| {
+            self.cell_or_default(origin.x + x as i64, origin.y + y as i64)
+                .clone()
+        });
+        (grid, origin)
+    }
+
+    /// The bounding box drawn row by row with `f` for every cell, set or not.
+    pub fn render(&self, mut f: impl FnMut(&T) -> char) -> String {
+        let mut out = String::new();
+        if let Some((min, max)) = self.bounds {
+            for y in min.y..=max.y {
+                for x in min.x..=max.x {
+                    out.push(f(self.cell_or_default(x, y)));
+                }
+                out.push('\n');
+            }
+        }
+        out
+    }
+
+    fn grow_bounds(&mut self, pos: IVec2) {
+        self.bounds = Some(grown(self.bounds, pos));
+    }
+
+    fn recompute_bounds(&mut self) {
+        self.bounds = self
+            .cells
+            .keys()
+            .fold(None, |bounds, pos| Some(grown(bounds, *pos)));
+    }
+}
+
+/// The smallest box around both `bounds` and `pos`.
+fn grown(bounds: Option<(IVec2, IVec2)>, pos: IVec2) -> (IVec2, IVec2) {
+    match bounds {
+        None => (pos, pos),
+        Some((min, max)) => (
+            IVec2 {
+                x: min.x.min(pos.x),
+                y: min.y.min(pos.y),
+            },
+            IVec2 {
+                x: max.x.max(pos.x),
+                y: max.y.max(pos.y),
+            },
+        ),
+    }
+}
+
+impl<T> Index<(i64, i64)> for SparseGrid<T> {
+    type Output = T;
+
+    /// The cell at `(x, y)`, panics if it isn't set.
+    fn index(&self, (x, y): (i64, i64)) -> &Self::Output {
+        self.get(x, y)
+            .unwrap_or_else(|| panic!("cell {x}, {y} isn't set"))
+    }
+}
+
+impl<T> IndexMut<(i64, i64)> for SparseGrid<T> {
+    fn index_mut(&mut self, (x, y): (i64, i64)) -> &mut Self::Output {
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("cell {x}, {y} isn't set"))
+    }
+}
+
+impl Display for SparseGrid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render(|v| *v))
+    }
+}
@@ -0,0 +1,86 @@
+use common::bigint::{BigInt, BigUint, ParseBigIntError};
+
+/// Values around the digit boundaries where carries and borrows go wrong.
+const SAMPLES: [i128; 12] = [
+    0,
+    1,
+    -1,
+    7,
+    -2024,
+    u32::MAX as i128,
+    u32::MAX as i128 + 1,
+    -(u32::MAX as i128),
+    10_000_000_000_000,
+    u64::MAX as i128,
+    -(u64::MAX as i128) - 3,
+    123_456_789_012_345_678_901_234_567,
+];
+
+#[test]
+fn matches_i128_arithmetic() {
+    for a in SAMPLES {
+        for b in SAMPLES {
+            let (big_a, big_b) = (BigInt::from(a), BigInt::from(b));
+
+            assert_eq!(a.cmp(&b), big_a.cmp(&big_b), "{a} cmp {b}");
+            assert_eq!((&big_a + &big_b).to_i128(), a.checked_add(b), "{a} + {b}");
+            assert_eq!((&big_a - &big_b).to_i128(), a.checked_sub(b), "{a} - {b}");
+            if let Some(product) = a.checked_mul(b) {
+                assert_eq!((&big_a * &big_b).to_i128(), Some(product), "{a} * {b}");
+            }
+            if b != 0 {
+                let (quotient, remainder) = big_a.div_rem(&big_b);
+                assert_eq!(quotient.to_i128(), Some(a / b), "{a} / {b}");
+                assert_eq!(remainder.to_i128(), Some(a % b), "{a} % {b}");
+            }
+        }
+    }
+}
+
+#[test]
+fn goes_past_the_primitive_types() {
+    let two = BigUint::from(2u32);
+    let big = two.pow(200) + BigUint::one();
+    assert_eq!(
+        big.to_string(),
+        "1606938044258990275541962092341162602522202993782792835301377"
+    );
+    assert_eq!(big.bits(), 201);
+    assert_eq!(big.to_u128(), None);
+
+    let (quotient, remainder) = big.div_rem(&two.pow(100));
+    assert_eq!(quotient, two.pow(100));
+    assert_eq!(remainder, BigUint::one());
+
+    let square = BigInt::from(-(u64::MAX as i128)).pow(2);
+    assert_eq!(
+        square.to_string(),
+        "340282366920938463426481119284349108225"
+    );
+    assert_eq!(
+        (square.clone() / BigInt::from(u64::MAX)).to_i128(),
+        Some(u64::MAX as i128)
+    );
+    assert_eq!(format!("{:>45}", -square).len(), 45);
+}
+
+#[test]
+fn parses_what_it_prints() {
+    for text in [
+        "0",
+        "42",
+        "-42",
+        "1000000000",
+        "-1606938044258990275541962092341162602522",
+    ] {
+        assert_eq!(text.parse::<BigInt>().unwrap().to_string(), text);
+    }
+    assert_eq!("+17".parse::<BigUint>(), Ok(BigUint::from(17u32)));
+    assert_eq!("-0".parse::<BigInt>(), Ok(BigInt::zero()));
+    assert_eq!("000123".parse::<BigUint>(), Ok(BigUint::from(123u32)));
+
+    for bad in ["", "-", "12a", "--1", "-+1", "1 2"] {
+        assert_eq!(bad.parse::<BigInt>(), Err(ParseBigIntError), "{bad:?}");
+    }
+    assert_eq!("-1".parse::<BigUint>(), Err(ParseBigIntError));
+}
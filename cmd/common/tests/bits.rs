@@ -0,0 +1,72 @@
+use common::bits::{BitGrid, BitSet};
+
+/// Indices on both sides of the word boundaries, where shifts and word lookups go wrong.
+const EDGES: [usize; 8] = [0, 1, 62, 63, 64, 65, 127, 128];
+
+#[test]
+fn sets_bits_across_word_boundaries() {
+    let mut set = BitSet::new(130);
+    assert!(set.is_empty());
+
+    for index in EDGES {
+        assert!(set.insert(index), "{index}");
+        assert!(!set.insert(index), "{index}");
+    }
+    assert_eq!(set.count(), EDGES.len());
+    assert_eq!(set.iter().collect::<Vec<_>>(), EDGES);
+    for index in [2, 61, 66, 126, 129] {
+        assert!(!set.contains(index), "{index}");
+    }
+
+    assert!(set.remove(63));
+    assert!(!set.remove(63));
+    assert!(set.contains(64));
+    assert_eq!(set.count(), EDGES.len() - 1);
+
+    set.clear();
+    assert!(set.is_empty());
+    assert_eq!(set.capacity(), 130);
+}
+
+#[test]
+fn combines_sets_word_by_word() {
+    let mut a = BitSet::new(200);
+    let mut b = BitSet::new(200);
+    for index in [3, 63, 64, 199] {
+        a.insert(index);
+    }
+    for index in [63, 64, 128] {
+        b.insert(index);
+    }
+
+    let mut union = a.clone();
+    union.union_with(&b);
+    assert_eq!(union.iter().collect::<Vec<_>>(), [3, 63, 64, 128, 199]);
+
+    a.intersect_with(&b);
+    assert_eq!(a.iter().collect::<Vec<_>>(), [63, 64]);
+}
+
+#[test]
+#[should_panic(expected = "index 130 out of range for bit set of capacity 130")]
+fn panics_past_the_capacity_inside_the_last_word() {
+    BitSet::new(130).contains(130);
+}
+
+#[test]
+fn keeps_layers_of_a_cell_apart() {
+    let mut grid = BitGrid::with_layers(9, 8, 4);
+    assert!(grid.insert_layer(8, 1, 3));
+    assert!(grid.insert_layer(0, 2, 0));
+    assert!(!grid.insert(0, 2));
+
+    assert!(grid.contains_layer(8, 1, 3));
+    assert!(!grid.contains(8, 1));
+    assert!(grid.contains_any(8, 1));
+    assert_eq!(grid.count(), 2);
+    assert_eq!(grid.cells().collect::<Vec<_>>(), [(8, 1), (0, 2)]);
+
+    assert!(grid.in_bounds(8, 7));
+    assert!(!grid.in_bounds(9, 0));
+    assert!(!grid.in_bounds(0, -1));
+}
@@ -0,0 +1,81 @@
+use common::{
+    geometry::{orientation, Line, Orientation, RVec2, Segment},
+    rational::Rational,
+    IVec2,
+};
+
+fn v(x: i64, y: i64) -> IVec2 {
+    IVec2 { x, y }
+}
+
+#[test]
+fn orientation_of_turns() {
+    assert_eq!(
+        orientation(v(0, 0), v(2, 0), v(2, 1)),
+        Orientation::CounterClockwise
+    );
+    assert_eq!(
+        orientation(v(0, 0), v(2, 0), v(2, -1)),
+        Orientation::Clockwise
+    );
+    assert_eq!(
+        orientation(v(0, 0), v(2, 2), v(-3, -3)),
+        Orientation::Collinear
+    );
+}
+
+#[test]
+fn lines_cross_at_exact_points() {
+    let a = Line::new(v(0, 0), v(3, 1));
+    let b = Line::new(v(0, 1), v(1, 0));
+    let crossing = a.intersection(&b).unwrap();
+
+    assert_eq!(
+        crossing,
+        RVec2 {
+            x: Rational::new(3, 4),
+            y: Rational::new(1, 4),
+        }
+    );
+    assert_eq!(crossing.to_ivec2(), None);
+    assert_eq!(
+        Line::through(v(0, 0), v(0, 1))
+            .intersection(&Line::through(v(5, 7), v(1, 0)))
+            .and_then(|p| p.to_ivec2()),
+        Some(v(0, 7))
+    );
+}
+
+#[test]
+fn parallel_and_collinear_lines_dont_cross() {
+    let line = Line::new(v(0, 0), v(2, 1));
+    let parallel = Line::new(v(0, 1), v(4, 3));
+    let same = Line::new(v(-2, -1), v(6, 3));
+
+    assert!(line.is_parallel(&parallel));
+    assert!(line.is_parallel(&same));
+    assert_eq!(line.intersection(&parallel), None);
+    assert_eq!(line.intersection(&same), None);
+    assert!(line.contains(v(8, 4)));
+    assert!(!parallel.contains(v(8, 4)));
+}
+
+#[test]
+fn segments_only_meet_within_their_ends() {
+    let segment = Segment::new(v(0, 0), v(4, 4));
+
+    assert_eq!(
+        segment.intersection(&Segment::new(v(0, 4), v(4, 0))),
+        Some(v(2, 2).into())
+    );
+    assert!(!segment.intersects(&Segment::new(v(5, 0), v(6, 0))));
+
+    // Collinear segments touching at one end meet there, overlapping ones have no single point
+    assert_eq!(
+        segment.intersection(&Segment::new(v(4, 4), v(6, 6))),
+        Some(v(4, 4).into())
+    );
+    assert!(segment.intersects(&Segment::new(v(2, 2), v(6, 6))));
+    assert_eq!(segment.intersection(&Segment::new(v(2, 2), v(6, 6))), None);
+    assert!(!segment.intersects(&Segment::new(v(5, 5), v(6, 6))));
+}
@@ -0,0 +1,85 @@
+use common::grid::Grid;
+
+/// 3 wide and 2 high, every cell different.
+fn numbered() -> Grid<u32> {
+    Grid::from_rows([[1, 2, 3], [4, 5, 6]])
+}
+
+#[test]
+fn rotations_and_flips_move_cells() {
+    let grid = numbered();
+
+    assert_eq!(grid.rotate_cw(), Grid::from_rows([[4, 1], [5, 2], [6, 3]]));
+    assert_eq!(grid.rotate_ccw(), Grid::from_rows([[3, 6], [2, 5], [1, 4]]));
+    assert_eq!(grid.rotate_180(), Grid::from_rows([[6, 5, 4], [3, 2, 1]]));
+    assert_eq!(grid.transpose(), Grid::from_rows([[1, 4], [2, 5], [3, 6]]));
+    assert_eq!(
+        grid.flip_horizontal(),
+        Grid::from_rows([[3, 2, 1], [6, 5, 4]])
+    );
+    assert_eq!(
+        grid.flip_vertical(),
+        Grid::from_rows([[4, 5, 6], [1, 2, 3]])
+    );
+}
+
+#[test]
+fn rotating_non_square_grids_swaps_width_and_height() {
+    let grid = numbered();
+    for rotated in [grid.rotate_cw(), grid.rotate_ccw(), grid.transpose()] {
+        assert_eq!((rotated.width(), rotated.height()), (2, 3));
+    }
+    let half_turn = grid.rotate_180();
+    assert_eq!((half_turn.width(), half_turn.height()), (3, 2));
+}
+
+#[test]
+fn full_turns_and_double_flips_are_the_identity() {
+    let grid = numbered();
+
+    assert_eq!(grid.rotate_cw().rotate_cw().rotate_cw().rotate_cw(), grid);
+    assert_eq!(
+        grid.rotate_ccw().rotate_ccw().rotate_ccw().rotate_ccw(),
+        grid
+    );
+    assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+    assert_eq!(grid.rotate_cw().rotate_cw(), grid.rotate_180());
+    assert_eq!(grid.flip_horizontal().flip_horizontal(), grid);
+    assert_eq!(grid.flip_vertical().flip_vertical(), grid);
+    assert_eq!(grid.transpose().transpose(), grid);
+}
+
+#[test]
+fn symmetries_are_distinct() {
+    let asymmetric = numbered();
+    let variants = asymmetric.symmetries().collect::<Vec<_>>();
+    assert_eq!(variants.len(), 8);
+    assert_eq!(variants[0], asymmetric);
+
+    // Mirrored left to right, so flips and rotations pair up
+    let mirrored = Grid::parse("M.S\n.A.\nM.S", |c| c);
+    assert_eq!(mirrored.symmetries().count(), 4);
+
+    let plus = Grid::parse(".#.\n###\n.#.", |c| c);
+    assert_eq!(plus.symmetries().count(), 1);
+
+    let square = Grid::from_rows([[1, 2], [3, 4]]);
+    assert_eq!(square.symmetries().count(), 8);
+}
+
+#[test]
+fn parse_and_display_round_trip() {
+    let text = "#..\n.#.\n";
+    let grid = Grid::parse(text, |c| c);
+
+    assert_eq!(grid.to_string(), text);
+    assert_eq!(grid.get(1, 1), Some(&'#'));
+    assert_eq!(grid.get(3, 0), None);
+    assert!(!grid.in_bounds(-1, 0));
+}
+
+#[test]
+#[should_panic(expected = "row 1 is 2 wide instead of 3")]
+fn ragged_rows_panic() {
+    Grid::from_rows([vec![1, 2, 3], vec![4, 5]]);
+}
@@ -0,0 +1,67 @@
+use common::{
+    grid_parser::{Count, GridParser, ParseError},
+    IVec2,
+};
+
+fn maze_parser() -> GridParser<impl FnMut(char) -> Option<bool>> {
+    GridParser::new(|c| match c {
+        '.' | 'S' | 'E' | 'o' => Some(false),
+        '#' => Some(true),
+        _ => None,
+    })
+    .marker('S', Count::ExactlyOne)
+    .marker('E', Count::AtLeastOne)
+    .marker('o', Count::Any)
+}
+
+#[test]
+fn collects_markers_row_by_row() {
+    let parsed = maze_parser().parse("#S.#\n#.E#\n#E.#").unwrap();
+
+    assert_eq!(parsed.grid.width(), 4);
+    assert_eq!(parsed.grid.height(), 3);
+    assert!(parsed.grid[(0, 0)]);
+    assert!(!parsed.grid[(1, 0)]);
+    assert_eq!(parsed.one('S'), IVec2 { x: 1, y: 0 });
+    assert_eq!(
+        parsed.all('E'),
+        [IVec2 { x: 2, y: 1 }, IVec2 { x: 1, y: 2 }]
+    );
+    assert!(parsed.all('o').is_empty());
+}
+
+#[test]
+fn enforces_marker_counts() {
+    let err = maze_parser().parse("#S.#\n#S.#\n#E.#").unwrap_err();
+    assert_eq!(
+        err,
+        ParseError::MarkerCount {
+            marker: 'S',
+            expected: Count::ExactlyOne,
+            found: 2,
+        }
+    );
+    assert_eq!(err.to_string(), "expected exactly one 'S' but found 2");
+
+    let err = maze_parser().parse("#S.#").unwrap_err();
+    assert_eq!(err.to_string(), "expected at least one 'E' but found 0");
+}
+
+#[test]
+fn reports_where_the_map_is_wrong() {
+    let err = maze_parser().parse("#S.#\n#.?E").unwrap_err();
+    assert_eq!(err, ParseError::UnknownChar { c: '?', x: 2, y: 1 });
+    assert_eq!(err.to_string(), "unknown char '?' at 2, 1");
+
+    let err = maze_parser().parse("#S.#\n#E#").unwrap_err();
+    assert_eq!(err.to_string(), "row 1 is 3 wide instead of 4");
+}
+
+#[test]
+fn skips_blank_lines_around_the_map() {
+    let parsed = maze_parser().parse("\n\n#S.#\n#.E#\n\n").unwrap();
+
+    assert_eq!(parsed.grid.height(), 2);
+    assert_eq!(parsed.one('S'), IVec2 { x: 1, y: 0 });
+    assert_eq!(parsed.one('E'), IVec2 { x: 2, y: 1 });
+}
@@ -0,0 +1,45 @@
+use std::borrow::Cow;
+
+use common::input::{normalize, Changes};
+
+#[test]
+fn windows_file_matches_unix_file() {
+    let unix = normalize("1 2\n3 4\n");
+    let windows = normalize("\u{feff}1 2 \r\n3 4\t\r\n\r\n");
+
+    assert_eq!(unix.text, "1 2\n3 4");
+    assert_eq!(windows.text, unix.text);
+    assert!(unix.changes.is_empty());
+    assert_eq!(
+        windows.changes,
+        Changes {
+            byte_order_mark: true,
+            crlf_line_endings: 3,
+            trailing_whitespace: 2,
+            leading_blank_lines: 0,
+            trailing_blank_lines: 1,
+        }
+    );
+    assert_eq!(
+        windows.changes.to_string(),
+        "removed the byte order mark, converted 3 CRLF line endings, \
+         trimmed whitespace after 2 lines, removed 1 trailing blank line"
+    );
+}
+
+#[test]
+fn example_literal_loses_leading_newline() {
+    let normalized = normalize("\n..#\n.#.\n\n#..\n");
+
+    assert_eq!(normalized.text, "..#\n.#.\n\n#..");
+    assert_eq!(normalized.changes.leading_blank_lines, 1);
+}
+
+#[test]
+fn clean_input_is_borrowed() {
+    assert!(matches!(
+        normalize("abc\ndef\n").text,
+        Cow::Borrowed("abc\ndef")
+    ));
+    assert!(matches!(normalize("").text, Cow::Borrowed("")));
+}
@@ -0,0 +1,178 @@
+use common::json::{Json, ParseJsonError};
+
+#[test]
+fn scalars() {
+    assert_eq!(Json::Null.to_string(), "null");
+    assert_eq!(Json::from(true).to_string(), "true");
+    assert_eq!(Json::from(-12i64).to_string(), "-12");
+    assert_eq!(Json::from(u64::MAX).to_string(), "18446744073709551615");
+    assert_eq!(Json::from(1.5).to_string(), "1.5");
+    assert_eq!(Json::from(2.0).to_string(), "2.0");
+    assert_eq!(Json::from(f64::NAN).to_string(), "null");
+    assert_eq!(Json::from(None::<u32>).to_string(), "null");
+    assert_eq!(Json::from(Some("x")).to_string(), "\"x\"");
+}
+
+#[test]
+fn string_escapes() {
+    assert_eq!(
+        Json::from("quote \" backslash \\ newline \n tab \t bell \u{7} ünïcode").to_string(),
+        r#""quote \" backslash \\ newline \n tab \t bell \u0007 ünïcode""#
+    );
+}
+
+#[test]
+fn arrays_and_objects_are_compact() {
+    assert_eq!(Json::Array(Vec::new()).to_string(), "[]");
+    assert_eq!(Json::from(vec![1, 2, 3]).to_string(), "[1,2,3]");
+    assert_eq!(Json::object([]).to_string(), "{}");
+
+    let value = Json::object([
+        ("b", 1.into()),
+        ("a", Json::from(vec!["x", "y"])),
+        ("nested", Json::object([("key", Json::Null)])),
+    ]);
+    assert_eq!(
+        value.to_string(),
+        r#"{"b":1,"a":["x","y"],"nested":{"key":null}}"#
+    );
+}
+
+#[test]
+fn parses_what_it_writes() {
+    let value = Json::object([
+        ("year", 2024.into()),
+        ("answer", "quote \" backslash \\ bell \u{7} ünïcode".into()),
+        ("error", Json::Null),
+        ("big", Json::from(u64::MAX)),
+        ("ratio", (-1.5).into()),
+        (
+            "nested",
+            Json::from(vec![Json::from(true), Json::object([])]),
+        ),
+    ]);
+
+    assert_eq!(value.to_string().parse::<Json>(), Ok(value.clone()));
+    assert_eq!(value.get("year").and_then(Json::as_int), Some(2024));
+    assert!(value.get("error").is_some_and(Json::is_null));
+    assert_eq!(value.get("missing"), None);
+    assert_eq!(Json::Null.get("year"), None);
+}
+
+#[test]
+fn parses_strings_with_escapes() {
+    for (text, expected) in [
+        (r#""""#, ""),
+        (r#""plain ünïcode 🎄""#, "plain ünïcode 🎄"),
+        (r#""\" \\ \/""#, "\" \\ /"),
+        (r#""\b\f\n\r\t""#, "\u{8}\u{c}\n\r\t"),
+        (r#""\u0041\u00e9\u2603""#, "Aé☃"),
+        // A character outside the basic plane as a surrogate pair
+        (r#""\ud83c\udf84""#, "🎄"),
+    ] {
+        assert_eq!(text.parse::<Json>(), Ok(Json::from(expected)), "{text}");
+    }
+}
+
+#[test]
+fn parses_numbers() {
+    for (text, expected) in [
+        ("0", Json::Int(0)),
+        ("-0", Json::Int(0)),
+        ("42", Json::Int(42)),
+        ("-17", Json::Int(-17)),
+        ("18446744073709551615", Json::Int(u64::MAX as i128)),
+        ("2.5", Json::Float(2.5)),
+        ("-0.125", Json::Float(-0.125)),
+        ("2.5e1", Json::Float(25.0)),
+        ("1E-2", Json::Float(0.01)),
+        ("4e+2", Json::Float(400.0)),
+        // Integers too big for an i128 still parse, as floats
+        (
+            "1000000000000000000000000000000000000000",
+            Json::Float(1e39),
+        ),
+    ] {
+        assert_eq!(text.parse::<Json>(), Ok(expected), "{text}");
+    }
+    assert_eq!(
+        "1e400".parse::<Json>().map(|v| v.to_string()),
+        Ok("null".to_string())
+    );
+}
+
+#[test]
+fn parses_nested_values() {
+    let value: Json = r#" { "a" : [ 1 , [ [ ] , { } ] , null ] ,
+        "b": { "c": { "d": [true, false] } }, "a": "again" } "#
+        .parse()
+        .unwrap();
+
+    assert_eq!(
+        value,
+        Json::object([
+            (
+                "a",
+                Json::Array(vec![
+                    Json::Int(1),
+                    Json::Array(vec![Json::Array(Vec::new()), Json::object([])]),
+                    Json::Null,
+                ])
+            ),
+            (
+                "b",
+                Json::object([("c", Json::object([("d", Json::from(vec![true, false]))]))])
+            ),
+            ("a", "again".into()),
+        ])
+    );
+    // Duplicate keys are kept, lookups find the first
+    assert_eq!(value.get("a").and_then(Json::as_int), None);
+    assert_eq!(
+        value
+            .get("b")
+            .and_then(|v| v.get("c"))
+            .and_then(|v| v.get("d")),
+        Some(&Json::from(vec![true, false]))
+    );
+}
+
+#[test]
+fn rejects_malformed_input() {
+    for (text, offset, message) in [
+        ("", 0, "unexpected end of input"),
+        ("   ", 3, "unexpected end of input"),
+        ("nul", 3, "expected a value"),
+        ("True", 0, "expected a value"),
+        ("[1,]", 3, "expected a value"),
+        ("[1 2]", 4, "expected ',' or ']'"),
+        ("[1", 2, "expected ',' or ']'"),
+        (r#"{"a" 1}"#, 5, "expected ':'"),
+        (r#"{"a":1,}"#, 7, "expected a string"),
+        ("{1:2}", 1, "expected a string"),
+        (r#"{"a":1"#, 6, "expected ',' or '}'"),
+        ("01", 0, "leading zero in number"),
+        ("-", 1, "expected a digit"),
+        ("1.", 2, "expected a digit"),
+        ("1e", 2, "expected a digit"),
+        ("+1", 0, "expected a value"),
+        (r#""abc"#, 4, "unterminated string"),
+        ("\"a\nb\"", 3, "control character in string"),
+        (r#""\x""#, 3, "bad escape"),
+        (r#""\u12g4""#, 6, "bad unicode escape"),
+        (r#""\ud83c""#, 8, "unpaired surrogate"),
+        (r#""\ud83c\u0041""#, 13, "unpaired surrogate"),
+        (r#""\udf84""#, 7, "unpaired surrogate"),
+        ("1 2", 2, "trailing characters"),
+    ] {
+        assert_eq!(
+            text.parse::<Json>().unwrap_err(),
+            ParseJsonError { offset, message },
+            "{text:?}"
+        );
+    }
+    assert_eq!(
+        "[".parse::<Json>().unwrap_err().to_string(),
+        "unexpected end of input at offset 1"
+    );
+}
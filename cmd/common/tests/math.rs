@@ -0,0 +1,95 @@
+use common::math::{crt, extended_gcd, gcd, isqrt, lcm, mod_inverse, mod_pow};
+
+#[test]
+fn gcd_and_lcm_ignore_signs() {
+    assert_eq!(gcd(12i64, -18), 6);
+    assert_eq!(gcd(0i64, 0), 0);
+    assert_eq!(lcm(-4i32, 6), 12);
+    assert_eq!(lcm(0i32, 6), 0);
+}
+
+#[test]
+fn extended_gcd_finds_bezout_coefficients() {
+    for (a, b) in [(240i64, 46), (240, -46), (-7, 3), (0, 5), (5, 0)] {
+        let (g, x, y) = extended_gcd(a, b);
+        assert_eq!(g, gcd(a, b), "gcd of {a}, {b}");
+        assert_eq!(a * x + b * y, g, "coefficients for {a}, {b}");
+    }
+}
+
+#[test]
+fn mod_inverse_only_for_coprime_numbers() {
+    assert_eq!(mod_inverse(3i64, 7), Some(5));
+    // -3 is 4 mod 7, and 4 * 2 is 8
+    assert_eq!(mod_inverse(-3i64, 7), Some(2));
+    assert_eq!(mod_inverse(6i64, 9), None);
+    assert_eq!(mod_inverse(0i64, 9), None);
+}
+
+#[test]
+fn mod_pow_by_squaring() {
+    assert_eq!(mod_pow(2i64, 10, 1000), 24);
+    assert_eq!(mod_pow(-2i64, 3, 5), 2);
+    assert_eq!(mod_pow(7i64, 0, 1), 0);
+    assert_eq!(
+        mod_pow(3i128, 1_000_000_000_000_000_000, 1_000_000_007),
+        246_336_683
+    );
+}
+
+#[test]
+fn crt_with_coprime_moduli_and_negative_residues() {
+    assert_eq!(crt(&[(2i64, 3), (3, 5), (2, 7)]), Some((23, 105)));
+    // -1 is 4 mod 5
+    assert_eq!(crt(&[(-1i64, 5), (2, 3)]), Some((14, 15)));
+    assert_eq!(crt::<i64>(&[]), Some((0, 1)));
+}
+
+#[test]
+fn crt_with_non_coprime_moduli() {
+    // 10 is 2 mod 4 and 4 mod 6, the answer repeats every lcm(4, 6) = 12 steps
+    assert_eq!(crt(&[(2i64, 4), (4, 6)]), Some((10, 12)));
+    assert_eq!(crt(&[(3i64, 6), (3, 9), (0, 3)]), Some((3, 18)));
+    // x odd for 4 and even for 6
+    assert_eq!(crt(&[(1i64, 4), (2, 6)]), None);
+    assert_eq!(crt(&[(1i64, 6), (2, 9)]), None);
+}
+
+#[test]
+fn crt_in_i128_past_i64() {
+    let (a, b) = (1_000_000_000_000_037i128, 999_999_999_999_989);
+    assert_eq!(
+        crt(&[(5, a), (-7, b)]),
+        Some((
+            250_000_000_000_006_249_999_999_999_894,
+            1_000_000_000_000_025_999_999_999_999_593
+        ))
+    );
+}
+
+#[test]
+fn isqrt_around_perfect_squares() {
+    for n in [0i64, 1, 2, 3, 4, 99, 100, 101] {
+        assert_eq!(isqrt(n), (n as f64).sqrt() as i64, "isqrt({n})");
+    }
+
+    let root = 3_037_000_499i64;
+    assert_eq!(isqrt(root * root - 1), root - 1);
+    assert_eq!(isqrt(root * root), root);
+    assert_eq!(isqrt(root * root + 1), root);
+    assert_eq!(isqrt(i64::MAX), root);
+}
+
+#[test]
+fn isqrt_at_i128_max() {
+    let root = 13_043_817_825_332_782_212i128;
+    assert_eq!(isqrt(i128::MAX), root);
+    assert_eq!(isqrt(root * root), root);
+    assert_eq!(isqrt(root * root - 1), root - 1);
+}
+
+#[test]
+#[should_panic(expected = "square root of negative")]
+fn isqrt_of_negative_panics() {
+    isqrt(-1i32);
+}
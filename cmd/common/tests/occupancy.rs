@@ -0,0 +1,81 @@
+use common::{occupancy::OccupancyGrid, IVec2};
+
+fn v(x: i64, y: i64) -> IVec2 {
+    IVec2 { x, y }
+}
+
+#[test]
+fn skips_the_middle_lines_on_odd_sizes() {
+    // 11x7 like the day 14 example, column 5 and row 3 belong to no quadrant
+    let grid = OccupancyGrid::from_positions(
+        11,
+        7,
+        [
+            v(0, 0),
+            v(4, 2),
+            v(4, 2),
+            v(6, 0),
+            v(10, 2),
+            v(0, 4),
+            v(10, 6),
+            v(6, 4),
+            v(5, 0),
+            v(0, 3),
+            v(5, 3),
+        ],
+    );
+
+    assert_eq!(grid.quadrant_counts(), [3, 2, 1, 2]);
+    assert_eq!(grid.total(), 11);
+}
+
+#[test]
+fn splits_even_sizes_in_halves() {
+    let grid = OccupancyGrid::from_positions(4, 2, [v(1, 0), v(2, 0), v(1, 1), v(3, 1), v(3, 1)]);
+    assert_eq!(grid.quadrant_counts(), [1, 1, 1, 2]);
+
+    // A single column or row is all middle
+    let line = OccupancyGrid::from_positions(1, 3, [v(0, 0), v(0, 2)]);
+    assert_eq!(line.quadrant_counts(), [0; 4]);
+}
+
+#[test]
+fn counts_entities_as_they_move() {
+    let mut grid = OccupancyGrid::new(5, 5);
+    grid.add(v(2, 2));
+    grid.add(v(2, 2));
+    grid.add(v(0, 0));
+    assert_eq!(grid.count_at(v(2, 2)), 2);
+    assert_eq!(grid.occupied_count(), 2);
+
+    grid.move_entity(v(2, 2), v(4, 4));
+    grid.move_entity(v(2, 2), v(4, 4));
+    assert!(!grid.is_occupied(v(2, 2)));
+    assert_eq!(grid.count_at(v(4, 4)), 2);
+    assert_eq!(grid.occupied_count(), 2);
+    assert!(!grid.remove(v(2, 2)));
+
+    assert_eq!(grid.count_at(v(-1, 0)), 0);
+    assert_eq!(grid.count_at(v(5, 0)), 0);
+    assert_eq!(
+        grid.occupied().collect::<Vec<_>>(),
+        [(v(0, 0), 1), (v(4, 4), 2)]
+    );
+}
+
+#[test]
+fn clips_neighbourhoods_at_the_edges() {
+    let grid = OccupancyGrid::from_positions(3, 3, [v(0, 0), v(1, 1), v(1, 1), v(2, 2)]);
+
+    assert_eq!(grid.neighbourhood(v(0, 0), 1).count(), 4);
+    assert_eq!(grid.count_around(v(0, 0), 1), 3);
+    assert_eq!(grid.occupied_around(v(0, 0), 1), 2);
+    assert_eq!(grid.count_around(v(1, 1), 1), 4);
+    assert_eq!(grid.count_around(v(1, 1), 0), 2);
+}
+
+#[test]
+#[should_panic(expected = "cell 3, 0 out of range for 3x3 occupancy grid")]
+fn panics_adding_off_the_grid() {
+    OccupancyGrid::new(3, 3).add(v(3, 0));
+}
@@ -0,0 +1,58 @@
+use common::path_arena::PathArena;
+
+#[test]
+fn branches_share_their_prefix() {
+    let mut arena = PathArena::new();
+    let start = arena.start('a');
+    let b = arena.extend(start, 'b');
+    let left = arena.extend(b, 'l');
+    let right = arena.extend(b, 'r');
+    let right_further = arena.extend(right, 's');
+
+    // Two branches off a-b only store their own steps
+    assert_eq!(arena.node_count(), 5);
+    assert_eq!(arena.to_vec(left), ['a', 'b', 'l']);
+    assert_eq!(arena.to_vec(right_further), ['a', 'b', 'r', 's']);
+    assert_eq!(arena.to_vec(b), ['a', 'b']);
+
+    assert_eq!(arena.len(start), 1);
+    assert_eq!(arena.len(right_further), 4);
+    assert_eq!(*arena.last(left), 'l');
+    assert_eq!(arena.parent(right_further), Some(right));
+    assert_eq!(arena.parent(start), None);
+}
+
+#[test]
+fn iterates_from_last_to_first() {
+    let mut arena = PathArena::new();
+    let mut path = arena.start(0);
+    for step in 1..5 {
+        path = arena.extend(path, step);
+    }
+    let branch = arena.extend(arena.parent(path).unwrap(), 10);
+
+    assert_eq!(
+        arena.iter(path).copied().collect::<Vec<_>>(),
+        [4, 3, 2, 1, 0]
+    );
+    assert_eq!(
+        arena.iter(branch).copied().collect::<Vec<_>>(),
+        [10, 3, 2, 1, 0]
+    );
+    assert!(arena.contains(branch, &0));
+    assert!(arena.contains(branch, &10));
+    assert!(!arena.contains(branch, &4));
+    assert!(!arena.contains(path, &10));
+}
+
+#[test]
+fn keeps_separate_starts_apart() {
+    let mut arena = PathArena::new();
+    let first = arena.start("x");
+    let second = arena.start("y");
+    let second = arena.extend(second, "z");
+
+    assert_eq!(arena.to_vec(first), ["x"]);
+    assert_eq!(arena.to_vec(second), ["y", "z"]);
+    assert!(!arena.contains(second, &"x"));
+}
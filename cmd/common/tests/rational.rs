@@ -0,0 +1,59 @@
+use common::rational::Rational;
+
+#[test]
+fn normalises_sign_and_lowest_terms() {
+    let half = Rational::new(1, 2);
+    assert_eq!(Rational::new(2, 4), half);
+    assert_eq!(Rational::new(-3, -6), half);
+
+    let negative = Rational::new(3, -6);
+    assert_eq!((negative.numer(), negative.denom()), (-1, 2));
+    assert_eq!(negative, -half);
+
+    let zero = Rational::new(0, -7);
+    assert_eq!((zero.numer(), zero.denom()), (0, 1));
+    assert_eq!(zero, Rational::ZERO);
+}
+
+#[test]
+#[should_panic(expected = "zero denominator")]
+fn zero_denominator_panics() {
+    Rational::new(1, 0);
+}
+
+#[test]
+fn arithmetic_stays_exact() {
+    let third = Rational::new(1, 3);
+    let sum = third + third + third;
+    assert_eq!(sum, Rational::ONE);
+    assert_eq!(sum.to_integer(), Some(1));
+
+    assert_eq!(
+        Rational::new(2, 3) * Rational::new(3, 4),
+        Rational::new(1, 2)
+    );
+    assert_eq!(
+        Rational::new(1, 2) / Rational::new(-1, 4),
+        Rational::from(-2)
+    );
+    assert_eq!(
+        Rational::new(1, 2) - Rational::new(3, 4),
+        Rational::new(-1, 4)
+    );
+    assert_eq!(Rational::new(-2, 5).recip(), Rational::new(-5, 2));
+    assert_eq!(Rational::new(2, 3).to_integer(), None);
+}
+
+#[test]
+fn rounds_and_orders() {
+    assert_eq!(Rational::new(7, 2).floor(), 3);
+    assert_eq!(Rational::new(7, 2).ceil(), 4);
+    assert_eq!(Rational::new(-7, 2).floor(), -4);
+    assert_eq!(Rational::new(-7, 2).ceil(), -3);
+    assert_eq!(Rational::from(5).ceil(), 5);
+
+    assert!(Rational::new(-1, 2) < Rational::new(1, 3));
+    assert!(Rational::new(2, 3) > Rational::new(3, 5));
+    assert_eq!(Rational::new(-3, 4).to_string(), "-3/4");
+    assert_eq!(Rational::new(8, 4).to_string(), "2");
+}
@@ -0,0 +1,92 @@
+use std::ffi::OsStr;
+
+use common::{
+    grid::Grid,
+    render::{color_enabled_from, Cell, Color, Renderer, Style},
+};
+
+fn maze() -> Grid<char> {
+    Grid::parse("#.#\n..#\n#..", |c| c)
+}
+
+fn draw(renderer: &Renderer) -> String {
+    renderer.render_grid(&maze(), |c| Cell::from(*c))
+}
+
+#[test]
+fn plain_text_keeps_highlights_and_shows_marks() {
+    let renderer = Renderer::new()
+        .color(false)
+        .highlight("region", [(1, 0), (1, 1)], Style::default().bg(Color::Blue))
+        .mark(
+            "path",
+            [(0, 1), (1, 1)],
+            "O",
+            Style::default().fg(Color::Red),
+        );
+
+    // The highlight only has a background, so neither it nor its legend shows without colour
+    assert_eq!(draw(&renderer), "#.#\nOO#\n#..\nO path\n");
+}
+
+#[test]
+fn colour_switches_style_only_where_it_changes() {
+    let red = Style::default().fg(Color::Red);
+    let renderer = Renderer::new()
+        .color(true)
+        .mark("path", [(0, 1), (1, 1)], "O", red)
+        .highlight("region", [(1, 1), (2, 1)], Style::default().bg(Color::Blue));
+
+    assert_eq!(
+        draw(&renderer),
+        concat!(
+            "#.#\n",
+            "\x1b[0;31mO\x1b[0;31;44mO\x1b[0;44m#\x1b[0m\n",
+            "#..\n",
+            "\x1b[0;31mO\x1b[0m path\n",
+            "\x1b[0;44m \x1b[0m region\n",
+        )
+    );
+}
+
+#[test]
+fn lists_the_legend_below_the_grid() {
+    let bold = Style::default().fg(Color::Green).bold();
+    let renderer = Renderer::new()
+        .color(false)
+        .legend(Cell::styled("#", bold), "wall")
+        .legend('.', "floor");
+
+    let plain = renderer.render(2, 1, |x, _| if x == 0 { '#' } else { '.' }.into());
+    assert_eq!(plain, "#.\n# wall\n. floor\n");
+
+    let coloured = Renderer::new()
+        .color(true)
+        .legend(Cell::styled("#", bold), "wall")
+        .render(1, 1, |_, _| Cell::styled("#", bold));
+    assert_eq!(
+        coloured,
+        "\x1b[0;1;32m#\x1b[0m\n\x1b[0;1;32m#\x1b[0m wall\n"
+    );
+}
+
+#[test]
+fn colour_only_on_a_terminal_without_no_color() {
+    assert!(color_enabled_from(None, true));
+    assert!(!color_enabled_from(None, false));
+
+    // Set to anything but empty turns colour off
+    assert!(!color_enabled_from(Some(OsStr::new("1")), true));
+    assert!(!color_enabled_from(Some(OsStr::new("0")), true));
+    assert!(color_enabled_from(Some(OsStr::new("")), true));
+    assert!(!color_enabled_from(Some(OsStr::new("")), false));
+}
+
+#[test]
+fn marks_without_colour_are_plain_text() {
+    let renderer =
+        Renderer::new()
+            .color(false)
+            .mark("start", [(1, 1)], "S", Style::default().bold());
+    assert_eq!(draw(&renderer), "#.#\n.S#\n#..\nS start\n");
+}
@@ -0,0 +1,99 @@
+use common::search::{bfs, count_paths, dfs};
+
+/// 0 splits into 1 and 2 which meet again at 3, 2 also goes around through 4, all ending at 5.
+fn diamond(node: u32) -> Vec<u32> {
+    match node {
+        0 => vec![1, 2],
+        1 => vec![3],
+        2 => vec![3, 4],
+        3 | 4 => vec![5],
+        _ => vec![],
+    }
+}
+
+#[test]
+fn bfs_finds_shortest_depths_and_paths() {
+    let search = bfs([0], diamond);
+
+    assert_eq!(search.order(), [0, 1, 2, 3, 4, 5]);
+    assert_eq!(search.depth(0), Some(0));
+    assert_eq!(search.depth(4), Some(2));
+    assert_eq!(search.depth(5), Some(3));
+    assert_eq!(search.parent(0), None);
+    assert_eq!(search.parent(4), Some(2));
+    assert_eq!(search.path_to(5), Some(vec![0, 1, 3, 5]));
+    assert_eq!(search.path_to(6), None);
+}
+
+#[test]
+fn bfs_with_overlapping_and_repeated_starts() {
+    let search = bfs([0, 3, 0], diamond);
+
+    assert_eq!(search.len(), 6);
+    assert_eq!(search.order(), [0, 3, 1, 2, 5, 4]);
+    assert_eq!(search.depth(3), Some(0));
+    assert_eq!(search.parent(3), None);
+    assert_eq!(search.path_to(5), Some(vec![3, 5]));
+}
+
+#[test]
+fn dfs_visits_neighbours_in_the_order_given() {
+    let search = dfs([0], diamond);
+
+    assert_eq!(search.order(), [0, 1, 3, 5, 2, 4]);
+    assert_eq!(search.parent(3), Some(1));
+    assert_eq!(search.parent(5), Some(3));
+    assert_eq!(search.parent(4), Some(2));
+}
+
+#[test]
+fn dfs_keeps_the_parent_it_reached_a_node_from() {
+    // 2 is a neighbour of 0, but 1 is followed first and reaches 2 before 0 gets back to it
+    let search = dfs([0], |node: u32| match node {
+        0 => vec![1, 2],
+        1 => vec![2],
+        _ => vec![],
+    });
+
+    assert_eq!(search.order(), [0, 1, 2]);
+    assert_eq!(search.parent(2), Some(1));
+    assert_eq!(search.depth(2), Some(2));
+    assert_eq!(search.path_to(2), Some(vec![0, 1, 2]));
+}
+
+#[test]
+fn dfs_with_repeated_starts_visits_them_once() {
+    let search = dfs([4, 0, 4], diamond);
+
+    assert_eq!(search.order(), [4, 5, 0, 1, 3, 2]);
+    assert_eq!(search.depth(4), Some(0));
+    assert_eq!(search.parent(5), Some(4));
+}
+
+#[test]
+fn count_paths_through_a_dag() {
+    let counts = count_paths([0], diamond);
+
+    assert_eq!(counts[&0], 1);
+    assert_eq!(counts[&3], 2);
+    assert_eq!(counts[&4], 1);
+    assert_eq!(counts[&5], 3);
+    assert_eq!(counts.get(&6), None);
+}
+
+#[test]
+fn count_paths_from_overlapping_starts() {
+    // 2 is a start and also reached from 0, so it ends two paths
+    let counts = count_paths([0, 2], diamond);
+
+    assert_eq!(counts[&2], 2);
+    assert_eq!(counts[&3], 3);
+    assert_eq!(counts[&5], 5);
+    assert_eq!(count_paths([0, 0], diamond), count_paths([0], diamond));
+}
+
+#[test]
+#[should_panic(expected = "graph has a cycle")]
+fn count_paths_panics_on_a_cycle() {
+    count_paths([0], |node: u32| [(node + 1) % 3]);
+}
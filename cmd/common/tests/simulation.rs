@@ -0,0 +1,113 @@
+use common::simulation::{Cycle, Simulation};
+
+/// x -> (x² + 1) mod n, the sequence Pollard's rho walks.
+struct Rho(u64);
+
+impl Simulation for Rho {
+    type State = u64;
+    type Key = u64;
+
+    fn step(&self, state: &mut u64) {
+        *state = (*state * *state + 1) % self.0;
+    }
+
+    fn key(&self, state: &u64) -> u64 {
+        *state
+    }
+}
+
+/// Counts up to 5, then loops through 5..12.
+struct Lasso;
+
+impl Simulation for Lasso {
+    type State = u64;
+    type Key = u64;
+
+    fn step(&self, state: &mut u64) {
+        *state = if *state < 5 {
+            *state + 1
+        } else {
+            5 + (*state - 4) % 7
+        };
+    }
+
+    fn key(&self, state: &u64) -> u64 {
+        *state
+    }
+}
+
+#[test]
+fn floyd_and_brent_find_the_known_cycle() {
+    let cycle = Cycle {
+        start: 5,
+        length: 7,
+    };
+    assert_eq!(Lasso.floyd(&0), cycle);
+    assert_eq!(Lasso.brent(&0), cycle);
+
+    // Starting on the cycle there's no tail
+    let on_cycle = Cycle {
+        start: 0,
+        length: 7,
+    };
+    assert_eq!(Lasso.floyd(&8), on_cycle);
+    assert_eq!(Lasso.brent(&8), on_cycle);
+}
+
+#[test]
+fn floyd_and_brent_agree_on_rho_sequences() {
+    for (n, start, mu, lambda) in [(1000, 2, 2, 6), (10403, 3, 12, 126), (99991, 7, 48, 525)] {
+        let cycle = Cycle {
+            start: mu,
+            length: lambda,
+        };
+        assert_eq!(Rho(n).floyd(&start), cycle, "floyd mod {n}");
+        assert_eq!(Rho(n).brent(&start), cycle, "brent mod {n}");
+    }
+}
+
+#[test]
+fn state_at_matches_stepping() {
+    let rho = Rho(99991);
+    let mut state = 7;
+    for n in 0..2000 {
+        assert_eq!(rho.state_at(&7, n), state, "step {n}");
+        rho.step(&mut state);
+    }
+}
+
+#[test]
+fn state_at_skips_whole_cycles() {
+    let cycle = Rho(10403).brent(&3);
+    let far = 1_000_000_000_000_000;
+    let mut expected = 3;
+    Rho(10403).step_n(&mut expected, cycle.equivalent_step(far));
+
+    assert_eq!(Rho(10403).state_at(&3, far), expected);
+    // A whole number of laps past any step lands on the same state
+    assert_eq!(
+        Rho(10403).state_at(&3, 12 + 126 * 1_000_000_007),
+        Rho(10403).state_at(&3, 12)
+    );
+    assert_eq!(Lasso.state_at(&0, far), 5 + (far - 5) % 7);
+}
+
+#[test]
+fn equivalent_step_keeps_the_tail() {
+    let cycle = Cycle {
+        start: 5,
+        length: 7,
+    };
+    assert_eq!(cycle.equivalent_step(3), 3);
+    assert_eq!(cycle.equivalent_step(5), 5);
+    assert_eq!(cycle.equivalent_step(12), 5);
+    assert_eq!(cycle.equivalent_step(13), 6);
+}
+
+#[test]
+fn step_until_counts_steps() {
+    let mut state = 0;
+    assert_eq!(Lasso.step_until(&mut state, |v| *v == 9), 9);
+    assert_eq!(state, 9);
+    assert_eq!(Lasso.step_until(&mut state, |v| *v == 9), 0);
+}
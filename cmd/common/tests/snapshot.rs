@@ -0,0 +1,93 @@
+use std::{ffi::OsStr, path::PathBuf};
+
+use common::snapshot::{check_snapshot, diff_lines, update_requested};
+
+/// A fresh directory to keep the snapshots of one test in.
+fn snapshot_dir(test: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("common-snapshot-{}-{test}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn matching_snapshot_passes_and_stays() {
+    let path = snapshot_dir("match").join("grid.snap");
+    std::fs::write(&path, "#.\n.#\n").unwrap();
+
+    for update in [false, true] {
+        assert_eq!(check_snapshot(&path, "#.\n.#\n", update), Ok(()));
+    }
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "#.\n.#\n");
+}
+
+#[test]
+fn mismatch_fails_with_a_diff_unless_updating() {
+    let path = snapshot_dir("mismatch").join("grid.snap");
+    std::fs::write(&path, "#.\n.#\n").unwrap();
+
+    let err = check_snapshot(&path, "#.\n##\n", false).unwrap_err();
+    assert_eq!(
+        err,
+        format!(
+            "snapshot {} doesn't match, rerun with UPDATE_SNAPSHOTS=1 to accept the new output\n \
+             #.\n-.#\n+##\n",
+            path.display()
+        )
+    );
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "#.\n.#\n");
+
+    assert_eq!(check_snapshot(&path, "#.\n##\n", true), Ok(()));
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "#.\n##\n");
+    assert_eq!(check_snapshot(&path, "#.\n##\n", false), Ok(()));
+}
+
+#[test]
+fn missing_snapshot_fails_unless_updating() {
+    let path = snapshot_dir("missing").join("snapshots/grid.snap");
+
+    assert_eq!(
+        check_snapshot(&path, "#\n", false),
+        Err(format!(
+            "snapshot {} doesn't exist, rerun with UPDATE_SNAPSHOTS=1 to create it",
+            path.display()
+        ))
+    );
+    assert!(!path.exists());
+
+    // Creates the snapshots directory too
+    assert_eq!(check_snapshot(&path, "#\n", true), Ok(()));
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "#\n");
+}
+
+#[test]
+fn update_env_var_values() {
+    assert!(!update_requested(None));
+    assert!(!update_requested(Some(OsStr::new("0"))));
+    assert!(update_requested(Some(OsStr::new("1"))));
+    assert!(update_requested(Some(OsStr::new("yes"))));
+}
+
+#[test]
+fn diff_keeps_common_lines() {
+    assert_eq!(diff_lines("a\nb\nc\n", "a\nb\nc\n"), " a\n b\n c\n");
+    assert_eq!(
+        diff_lines("a\nb\nc\n", "a\nx\nc\nd\n"),
+        " a\n-b\n+x\n c\n+d\n"
+    );
+    assert_eq!(diff_lines("", "a\n"), "+a\n");
+}
+
+#[test]
+fn diff_shows_a_missing_trailing_newline() {
+    assert_eq!(
+        diff_lines("a\nb\n", "a\nb"),
+        " a\n-b\n+b\n\\ No newline at end of file\n"
+    );
+    assert_eq!(
+        diff_lines("a", "a\n"),
+        "-a\n\\ No newline at end of file\n+a\n"
+    );
+    // An extra empty line at the end is a line of its own
+    assert_eq!(diff_lines("a\n", "a\n\n"), " a\n+\n");
+}
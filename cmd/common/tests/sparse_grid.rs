@@ -0,0 +1,113 @@
+use common::{sparse_grid::SparseGrid, IVec2};
+
+fn v(x: i64, y: i64) -> IVec2 {
+    IVec2 { x, y }
+}
+
+#[test]
+fn negative_coordinates_and_defaults() {
+    let mut grid = SparseGrid::new('.');
+    grid.set(-3, -2, '#');
+    assert_eq!(*grid.get_or_default(-1, 4), '.');
+    *grid.get_or_default(-1, 4) = 'x';
+
+    assert_eq!(grid.get(-3, -2), Some(&'#'));
+    assert_eq!(grid[(-1, 4)], 'x');
+    assert_eq!(grid.get(-100, 100), None);
+    assert_eq!(*grid.cell_or_default(-100, 100), '.');
+    assert!(grid.contains(-3, -2));
+    assert!(!grid.contains(0, 0));
+    assert_eq!(grid.len(), 2);
+
+    // Replacing returns what was there, get_or_default set the default only once
+    assert_eq!(grid.set(-3, -2, 'o'), Some('#'));
+    assert_eq!(grid.len(), 2);
+}
+
+#[test]
+fn get_mut_and_index_only_reach_set_cells() {
+    let mut grid = SparseGrid::new(0);
+    grid.set(1, -1, 5);
+
+    *grid.get_mut(1, -1).unwrap() += 1;
+    grid[(1, -1)] *= 2;
+    assert_eq!(grid[(1, -1)], 12);
+
+    assert_eq!(grid.get_mut(0, 0), None);
+    assert_eq!(grid.get_at(IVec2 { x: 0, y: 0 }), None);
+    assert!(!grid.contains(0, 0));
+    assert_eq!(grid.bounds(), Some((v(1, -1), v(1, -1))));
+}
+
+#[test]
+#[should_panic(expected = "cell 2, 3 isn't set")]
+fn index_panics_on_unset_cells() {
+    let grid = SparseGrid::new(0);
+    let _ = grid[(2, 3)];
+}
+
+#[test]
+#[should_panic(expected = "cell -2, 3 isn't set")]
+fn index_mut_panics_on_unset_cells() {
+    let mut grid = SparseGrid::new(0);
+    grid[(-2, 3)] = 1;
+}
+
+#[test]
+fn bounds_grow_on_insert() {
+    let mut grid = SparseGrid::new(0);
+    assert_eq!(grid.bounds(), None);
+    assert_eq!((grid.width(), grid.height()), (0, 0));
+
+    grid.set(2, 3, 1);
+    assert_eq!(grid.bounds(), Some((v(2, 3), v(2, 3))));
+
+    grid.set(-1, 5, 1);
+    grid.set(4, -2, 1);
+    assert_eq!(grid.bounds(), Some((v(-1, -2), v(4, 5))));
+    assert_eq!((grid.width(), grid.height()), (6, 8));
+    assert!(grid.in_bounds(0, 0));
+    assert!(!grid.in_bounds(5, 0));
+}
+
+#[test]
+fn bounds_shrink_on_remove() {
+    let mut grid = SparseGrid::new(0);
+    grid.set(0, 0, 1);
+    grid.set(5, 1, 2);
+    grid.set(2, -4, 3);
+
+    // Inside the box, nothing changes
+    grid.set(1, 0, 4);
+    assert_eq!(grid.remove(1, 0), Some(4));
+    assert_eq!(grid.bounds(), Some((v(0, -4), v(5, 1))));
+
+    assert_eq!(grid.remove(5, 1), Some(2));
+    assert_eq!(grid.bounds(), Some((v(0, -4), v(2, 0))));
+    assert_eq!(grid.remove(5, 1), None);
+
+    grid.remove(2, -4);
+    assert_eq!(grid.bounds(), Some((v(0, 0), v(0, 0))));
+    grid.remove(0, 0);
+    assert_eq!(grid.bounds(), None);
+    assert!(grid.is_empty());
+}
+
+#[test]
+fn renders_the_bounding_box() {
+    let mut grid = SparseGrid::new('.');
+    assert_eq!(grid.to_string(), "");
+
+    grid.set(-1, -1, '#');
+    grid.set(1, 0, '@');
+    assert_eq!(grid.to_string(), "#..\n..@\n");
+    assert_eq!(
+        grid.render(|c| if *c == '.' { ' ' } else { '*' }),
+        "*  \n  *\n"
+    );
+
+    let (dense, origin) = grid.to_grid();
+    assert_eq!(origin, v(-1, -1));
+    assert_eq!((dense.width(), dense.height()), (3, 2));
+    assert_eq!(dense[(2, 1)], '@');
+}
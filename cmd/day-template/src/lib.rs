@@ -0,0 +1,17 @@
+pub const INPUT: &str = include_str!("input.txt");
+pub const EXAMPLE_INPUT: &str = r#"
+"#;
+
+pub fn part_1(_parsed_input: &ParsedInput) -> u64 {
+    0
+}
+
+pub fn part_2(_parsed_input: &ParsedInput) -> u64 {
+    0
+}
+
+pub struct ParsedInput {}
+
+pub fn parse_input(_input: &str) -> ParsedInput {
+    todo!();
+}
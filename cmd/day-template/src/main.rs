@@ -1,27 +1,19 @@
-const INPUT: &str = include_str!("input.txt");
-const EXAMPLE_INPUT: &str = r#"
-"#;
+use day_template::{parse_input, part_1, part_2, EXAMPLE_INPUT, INPUT};
 
 fn main() {
+    common::log::init_from_args();
+
     let use_example = std::env::args().any(|v| v == "--example");
 
     println!("Launching, using example. {use_example}");
 
-    let parsed = parse_input(use_example.then_some(EXAMPLE_INPUT).unwrap_or(INPUT));
+    let input = common::input::load(if use_example { EXAMPLE_INPUT } else { INPUT });
+    let parsed = parse_input(&input);
 
     println!("part 1");
-    part_1(&parsed);
+    println!("Total: {}", part_1(&parsed));
 
     println!("=======");
     println!("part 2");
-    part_2(&parsed);
-}
-
-fn part_1(parsed_input: &ParsedInput) {}
-fn part_2(parsed_input: &ParsedInput) {}
-
-struct ParsedInput {}
-
-fn parse_input(input: &str) -> ParsedInput {
-    todo!();
+    println!("Total: {}", part_2(&parsed));
 }